@@ -18,4 +18,12 @@ fn main() {
     println!("v: {:?}", v);
 
     assert!(v.into_iter().zip(0..size).all(|(i, j)| i == j));
+
+    // send the value `size * rank + r` to rank `r`
+    let send = (0..size).map(|r| size * rank + r).collect::<Vec<_>>();
+    let received = world.all_to_all_vec(&send[..]);
+    assert_eq!(
+        (0..size).map(|r| size * r + rank).collect::<Vec<_>>(),
+        received
+    );
 }
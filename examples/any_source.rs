@@ -0,0 +1,45 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+
+    if rank == root_rank {
+        let mut seen = vec![false; size as usize];
+        for _ in 1..size {
+            let (msg, status) = world.any_process().receive::<Rank>();
+            println!(
+                "Root received {} from rank {} with tag {}.",
+                msg,
+                status.source_rank(),
+                status.tag()
+            );
+            assert_eq!(status.source_rank(), msg);
+            assert_eq!(status.source_rank() * 10, status.tag());
+            seen[msg as usize] = true;
+        }
+        assert!(seen.iter().skip(1).all(|&x| x));
+        world.barrier();
+
+        // only match messages with a specific tag, but from any source
+        let (msg, status) = world.any_process().receive_with_tag::<Rank>(7);
+        assert_eq!(7, status.tag());
+        assert_eq!(size - 1, msg);
+    } else {
+        world
+            .process_at_rank(root_rank)
+            .send_with_tag(&rank, rank * 10);
+        world.barrier();
+        if rank == size - 1 {
+            world.process_at_rank(root_rank).send_with_tag(&rank, 7);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+type Tile = [f64; 4];
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = if rank > 0 { rank - 1 } else { size - 1 };
+    let previous_process = world.process_at_rank(previous_rank);
+
+    let tiles: Vec<Tile> = (0..3).map(|i| [f64::from(rank * 10 + i); 4]).collect();
+    let mut received: Vec<Tile> = vec![[0.0; 4]; 3];
+
+    let status = p2p::send_receive_into(
+        &tiles[..],
+        &next_process,
+        &mut received[..],
+        &previous_process,
+    );
+    println!(
+        "Rank {} received tiles: {:?}, status: {:?}.",
+        rank, received, status
+    );
+    assert_eq!(3, status.count(Tile::equivalent_datatype()));
+
+    let expected: Vec<Tile> = (0..3)
+        .map(|i| [f64::from(previous_rank * 10 + i); 4])
+        .collect();
+    assert_eq!(expected, received);
+}
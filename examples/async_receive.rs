@@ -0,0 +1,59 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use mpi::request::WaitGuard;
+use mpi::traits::*;
+
+/// A minimal executor that runs a single future to completion on the current thread
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank + size - 1) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    let msg = [rank, 2 * rank, 3 * rank];
+    let mut buf = [-1; 3];
+
+    mpi::request::scope(|scope| {
+        let _sreq = WaitGuard::from(next_process.immediate_send(scope, &msg[..]));
+
+        let status = block_on(async {
+            previous_process
+                .receive_into_async(scope, &mut buf[..])
+                .await
+        });
+        assert_eq!(previous_rank, status.source_rank());
+    });
+
+    println!("Rank {} received {:?}.", rank, buf);
+    assert_eq!([previous_rank, 2 * previous_rank, 3 * previous_rank], buf);
+}
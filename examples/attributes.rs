@@ -0,0 +1,61 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::sync::Arc;
+
+use mpi::topology::CommKeyval;
+use mpi::traits::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct LibraryState {
+    calls: usize,
+    shared: Arc<()>,
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let shared = Arc::new(());
+    let keyval = CommKeyval::<LibraryState>::new();
+    let private_keyval = CommKeyval::<u32>::without_copy();
+
+    let comm = world.duplicate();
+    assert_eq!(None, comm.get_attr(&keyval));
+
+    comm.set_attr(
+        &keyval,
+        LibraryState {
+            calls: 1,
+            shared: shared.clone(),
+        },
+    );
+    comm.set_attr(&private_keyval, 42);
+    assert_eq!(1, comm.get_attr(&keyval).unwrap().calls);
+    assert_eq!(Some(42), comm.get_attr(&private_keyval));
+
+    // replacing a value drops the old one
+    comm.set_attr(
+        &keyval,
+        LibraryState {
+            calls: 2,
+            shared: shared.clone(),
+        },
+    );
+    assert_eq!(2, comm.get_attr(&keyval).unwrap().calls);
+    assert_eq!(2, Arc::strong_count(&shared));
+
+    // values of `keyval` are cloned into duplicates, those of `private_keyval` are not
+    let copy = comm.duplicate();
+    assert_eq!(2, copy.get_attr(&keyval).unwrap().calls);
+    assert_eq!(None, copy.get_attr(&private_keyval));
+    assert_eq!(3, Arc::strong_count(&shared));
+
+    // freeing a communicator drops its values
+    drop(copy);
+    assert_eq!(2, Arc::strong_count(&shared));
+
+    comm.delete_attr(&keyval);
+    assert_eq!(None, comm.get_attr(&keyval));
+    assert_eq!(1, Arc::strong_count(&shared));
+}
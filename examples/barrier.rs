@@ -26,4 +26,32 @@ fn main() {
         world.barrier();
         world.process_at_rank(0).send(&2u64);
     }
+
+    // the timed barrier orders the messages in the same way
+    let waited = if world.rank() == receiver_rank {
+        let n = (world.size() - 1) as usize;
+        let mut buf = vec![0u64; 2 * n];
+        for x in buf[0..n].iter_mut() {
+            world.any_process().receive_into(x);
+        }
+        let waited = world.timed_barrier();
+        for x in buf[n..2 * n].iter_mut() {
+            world.any_process().receive_into(x);
+        }
+        assert!(buf[0..n].iter().all(|&x| x == 3));
+        assert!(buf[n..2 * n].iter().all(|&x| x == 4));
+        waited
+    } else {
+        world.process_at_rank(0).send(&3u64);
+        let waited = world.timed_barrier();
+        world.process_at_rank(0).send(&4u64);
+        waited
+    };
+    println!("Rank {} barrier imbalance: {} s", world.rank(), waited);
+
+    // the imbalance is the maximum over all processes, so every process reports the same value
+    let mut all_waited = vec![0.0f64; world.size() as usize];
+    world.all_gather_into(&waited, &mut all_waited[..]);
+    assert!(waited >= 0.0);
+    assert!(all_waited.iter().all(|&w| w == waited));
 }
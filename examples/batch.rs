@@ -0,0 +1,63 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::request::Batch;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_process = world.process_at_rank((rank + size - 1) % size);
+
+    // exchange halos with both neighbours in a ring
+    let halo = [rank; 2];
+    let mut from_previous = [-1; 2];
+    let mut from_next = [-1; 2];
+
+    mpi::request::scope(|scope| {
+        let statuses = scope
+            .batch(|b| {
+                b.receive_into_with_tag(&previous_process, &mut from_previous[..], 0);
+                b.receive_into_with_tag(&next_process, &mut from_next[..], 1);
+                b.send_with_tag(&next_process, &halo[..], 0);
+                b.send_with_tag(&previous_process, &halo[..], 1);
+            })
+            .wait_all();
+        assert_eq!(4, statuses.len());
+        assert_eq!(previous_process.rank(), statuses[0].source_rank());
+        assert_eq!(next_process.rank(), statuses[1].source_rank());
+    });
+
+    assert_eq!([previous_process.rank(); 2], from_previous);
+    assert_eq!([next_process.rank(); 2], from_next);
+
+    // a send/receive pair can be extended with more operations before waiting
+    let mut from_previous = -1;
+    let mut from_next = -1;
+    mpi::request::scope(|scope| {
+        let mut batch = scope.send_receive_immediate(
+            &rank,
+            &next_process,
+            &mut from_previous,
+            &previous_process,
+        );
+        batch.send_receive(&rank, &previous_process, &mut from_next, &next_process);
+        assert_eq!(4, batch.len());
+        batch.wait_all();
+    });
+    assert_eq!(previous_process.rank(), from_previous);
+    assert_eq!(next_process.rank(), from_next);
+
+    // a batch of receives that are never matched can be cancelled
+    let mut never = [0u8; 1];
+    mpi::request::scope(|scope| {
+        let mut batch = Batch::new(scope);
+        batch.receive_into_with_tag(&world.any_process(), &mut never[..], 42);
+        assert_eq!(1, batch.len());
+        batch.cancel_all();
+    });
+}
@@ -32,4 +32,39 @@ fn main() {
     root_process.broadcast_into(&mut a[..]);
     println!("Rank {} received value: {:?}.", world.rank(), &a[..]);
     assert_eq!(&a[..], &[2, 4, 8, 16]);
+    println!();
+
+    let mut y = if world.rank() == root_rank {
+        0.5_f64
+    } else {
+        0.0
+    };
+    root_process.broadcast(&mut y);
+    println!("Rank {} received value: {}.", world.rank(), y);
+    assert_eq!(y, 0.5);
+
+    let mut b = if world.rank() == root_rank {
+        vec![1_u8; 1 << 16]
+    } else {
+        vec![0_u8; 1 << 16]
+    };
+    root_process.broadcast_into_c(&mut b[..]);
+    assert!(b.iter().all(|&x| x == 1));
+
+    // only the root knows the length, the other processes start with vectors of any length
+    let mut c = if world.rank() == root_rank {
+        vec![3_i32, 1, 4, 1, 5]
+    } else {
+        vec![-1; world.rank() as usize]
+    };
+    root_process.broadcast_vec(&mut c);
+    assert_eq!(c, [3, 1, 4, 1, 5]);
+
+    let mut empty = if world.rank() == root_rank {
+        Vec::new()
+    } else {
+        vec![-1_i32; 3]
+    };
+    root_process.broadcast_vec(&mut empty);
+    assert!(empty.is_empty());
 }
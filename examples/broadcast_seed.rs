@@ -0,0 +1,30 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::seed_for_rank;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // all processes agree on the base seed
+    let seed = world.broadcast_seed(0);
+    let mut seeds = vec![0u64; size as usize];
+    world.all_gather_into(&seed, &mut seeds[..]);
+    assert!(seeds.iter().all(|&s| s == seed));
+
+    // but every process gets its own stream
+    let own = seed_for_rank(seed, rank);
+    world.all_gather_into(&own, &mut seeds[..]);
+    for (r, &s) in seeds.iter().enumerate() {
+        assert_eq!(seed_for_rank(seed, r as i32), s);
+        assert!(seeds[..r].iter().all(|&other| other != s));
+    }
+
+    // derived seeds only depend on the base seed and the rank
+    assert_eq!(seed_for_rank(42, 3), seed_for_rank(42, 3));
+    assert_ne!(seed_for_rank(42, 3), seed_for_rank(43, 3));
+}
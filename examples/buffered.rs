@@ -3,6 +3,7 @@ extern crate mpi;
 
 use mpi::request::WaitGuard;
 use mpi::traits::*;
+use mpi::Count;
 
 const BUFFER_SIZE: usize = 10 * 1024 * 1024;
 
@@ -30,4 +31,22 @@ fn main() {
         world.this_process().buffered_send(&x[..]);
     });
     assert_eq!(x, y);
+
+    // a buffer that fits exactly two messages like `x`
+    let message_size = world.pack_size(x.len() as Count, &f32::equivalent_datatype()) as usize;
+    let size = 2 * (message_size + mpi::environment::buffered_send_overhead());
+    universe.set_buffer_size(size);
+    assert_eq!(universe.buffer_size(), size);
+
+    let mut z = vec![0.0; 2 * x.len()];
+    {
+        let (z1, z2) = z.split_at_mut(x.len());
+        let process = world.this_process();
+        process.buffered_send_with_tag(&x[..], 1);
+        process.buffered_send_with_tag(&x[..], 2);
+        process.receive_into_with_tag(z2, 2);
+        process.receive_into_with_tag(z1, 1);
+    }
+    assert_eq!(&x[..], &z[..x.len()]);
+    assert_eq!(&x[..], &z[x.len()..]);
 }
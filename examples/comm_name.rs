@@ -11,4 +11,12 @@ fn main() {
     assert_eq!("MPI_COMM_WORLD", world.get_name());
     world.set_name(CNAME);
     assert_eq!(CNAME, world.get_name());
+
+    // overly long names are truncated
+    let long_name = "x".repeat(1000);
+    world.set_name(&long_name);
+    let name = world.get_name();
+    assert!(!name.is_empty());
+    assert!(name.len() < long_name.len());
+    assert!(long_name.starts_with(&name));
 }
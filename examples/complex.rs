@@ -0,0 +1,35 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::traits::*;
+use num_complex::Complex;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // complex values are reduced by the native complex operations of MPI
+    let x = Complex::new(1.0_f64, f64::from(rank));
+    let sum = world.all_reduce(&x, SystemOperation::sum());
+    assert_eq!(
+        Complex::new(f64::from(size), f64::from(size * (size - 1) / 2)),
+        sum
+    );
+
+    let x = [Complex::new(1.0_f32, 0.0), Complex::new(0.0, 1.0)];
+    let mut product = [Complex::new(0.0_f32, 0.0); 2];
+    world.all_reduce_into(&x[..], &mut product[..], SystemOperation::product());
+    assert_eq!(Complex::new(1.0, 0.0), product[0]);
+    assert_eq!(Complex::i().powi(size), product[1]);
+
+    let mut y = if rank == 0 {
+        Complex::new(0.5_f64, -0.5)
+    } else {
+        Complex::default()
+    };
+    world.process_at_rank(0).broadcast_into(&mut y);
+    assert_eq!(Complex::new(0.5, -0.5), y);
+}
@@ -0,0 +1,44 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Combiner, DecodedDatatype, UserDatatype};
+use mpi::traits::*;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let double = f64::equivalent_datatype();
+    assert_eq!(Combiner::Named, double.envelope().combiner);
+
+    // every other of 6 `f64`s
+    let vector = UserDatatype::vector(3, 1, 2, &double);
+    let envelope = vector.envelope();
+    assert_eq!(Combiner::Vector, envelope.combiner);
+    assert_eq!(
+        (3, 0, 1),
+        (
+            envelope.num_integers,
+            envelope.num_addresses,
+            envelope.num_datatypes
+        )
+    );
+
+    let contents = vector.contents();
+    assert_eq!(vec![3, 1, 2], contents.integers);
+    match &contents.datatypes[..] {
+        [DecodedDatatype::Named(oldtype)] => assert_eq!("MPI_DOUBLE", oldtype.get_name()),
+        _ => panic!("The old type of the vector should be a predefined datatype."),
+    }
+
+    // walk a datatype nested in another one
+    let contiguous = UserDatatype::contiguous(2, &vector);
+    let contents = contiguous.contents();
+    assert_eq!(vec![2], contents.integers);
+    match &contents.datatypes[..] {
+        [DecodedDatatype::Derived(oldtype)] => {
+            assert_eq!(Combiner::Vector, oldtype.envelope().combiner);
+            assert_eq!(vec![3, 1, 2], oldtype.contents().integers);
+        }
+        _ => panic!("The old type of the contiguous datatype should be a derived datatype."),
+    }
+}
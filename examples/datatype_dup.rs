@@ -1,9 +1,25 @@
 #![deny(warnings)]
 extern crate mpi;
 
-use mpi::datatype::DynBufferMut;
+use mpi::datatype::{DynBufferMut, UserDatatype};
 use mpi::traits::*;
 
+/// Keeps its own copy of a datatype handed in by the caller
+struct Library {
+    datatype: UserDatatype,
+}
+
+impl Library {
+    fn new<D>(datatype: &D) -> Library
+    where
+        D: Datatype + UncommittedDatatype<DuplicatedDatatype = UserDatatype>,
+    {
+        Library {
+            datatype: datatype.dup(),
+        }
+    }
+}
+
 fn main() {
     let universe = mpi::initialize().unwrap();
     let world = universe.world();
@@ -24,4 +40,20 @@ fn main() {
     root_process.broadcast_into(&mut buffer);
 
     assert_eq!([1, 2, 3, 4], ints);
+
+    // the duplicate stays valid after the original has been freed
+    let pair = UserDatatype::contiguous(2, &i32::equivalent_datatype());
+    let library = Library::new(&pair);
+    drop(pair);
+
+    let mut pairs = if world.rank() == 0 {
+        [5i32, 6, 7, 8]
+    } else {
+        [0, 0, 0, 0]
+    };
+    let mut buffer =
+        unsafe { DynBufferMut::from_raw(pairs.as_mut_ptr(), 2, library.datatype.as_ref()) };
+    root_process.broadcast_into(&mut buffer);
+
+    assert_eq!([5, 6, 7, 8], pairs);
 }
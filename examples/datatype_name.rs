@@ -0,0 +1,23 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::UserDatatype;
+use mpi::traits::*;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    assert_eq!("MPI_DOUBLE", f64::equivalent_datatype().get_name());
+
+    let pair = UserDatatype::contiguous(2, &i32::equivalent_datatype());
+    pair.set_name("pair of ints");
+    assert_eq!("pair of ints", pair.get_name());
+
+    // overly long names are truncated
+    let long_name = "ä".repeat(1000);
+    pair.set_name(&long_name);
+    let name = pair.get_name();
+    assert!(!name.is_empty());
+    assert!(name.len() < long_name.len());
+    assert!(long_name.starts_with(&name));
+}
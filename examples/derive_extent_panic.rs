@@ -0,0 +1,15 @@
+use mpi::traits::Equivalence;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    // Ensures that rsmpi panics if the derived datatype does not cover the size of the type, here
+    // because of an alignment that cannot be inferred from the fields.
+    assert!(std::panic::catch_unwind(|| {
+        #[derive(Equivalence)]
+        #[repr(C, align(16))]
+        struct CheckExtentPanic(u8);
+        let _ = CheckExtentPanic::equivalent_datatype();
+    })
+    .is_err());
+}
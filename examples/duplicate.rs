@@ -1,7 +1,7 @@
 #![deny(warnings)]
 extern crate mpi;
 
-use mpi::topology::CommunicatorRelation;
+use mpi::topology::{Color, CommunicatorRelation, GroupRelation};
 use mpi::traits::*;
 
 fn main() {
@@ -13,4 +13,59 @@ fn main() {
     moon.barrier();
 
     assert_eq!(CommunicatorRelation::Congruent, world.compare(&moon));
+    assert_eq!(
+        GroupRelation::Identical,
+        world.group().compare(&moon.group())
+    );
+    assert_eq!(world.size(), moon.size());
+    assert_eq!(world.rank(), moon.rank());
+
+    // rank and size are cached, the cached values agree with the MPI library
+    let (mut raw_rank, mut raw_size) = (-1, -1);
+    unsafe {
+        mpi::ffi::MPI_Comm_rank(moon.as_raw(), &mut raw_rank);
+        mpi::ffi::MPI_Comm_size(moon.as_raw(), &mut raw_size);
+    }
+    assert_eq!((raw_rank, raw_size), (moon.rank(), moon.size()));
+
+    assert_eq!(CommunicatorRelation::Identical, world.compare(&world));
+
+    // the duplicate only becomes available once the duplication has completed
+    let pending = world.immediate_duplicate();
+    let sun = pending.wait();
+    assert_eq!(CommunicatorRelation::Congruent, world.compare(&sun));
+    sun.barrier();
+
+    let mut pending = world.immediate_duplicate();
+    let star = loop {
+        match pending.test() {
+            Ok(star) => break star,
+            Err(p) => pending = p,
+        }
+    };
+    assert_eq!(CommunicatorRelation::Congruent, moon.compare(&star));
+
+    // same processes in reverse order
+    let reversed = world
+        .split_by_color_with_key(Color::with_value(0), world.size() - world.rank())
+        .unwrap();
+    let expected = if world.size() == 1 {
+        CommunicatorRelation::Congruent
+    } else {
+        CommunicatorRelation::Similar
+    };
+    assert_eq!(expected, world.compare(&reversed));
+
+    if world.size() > 1 {
+        let halves = world
+            .split_by_color(Color::with_value(world.rank() % 2))
+            .unwrap();
+        assert_eq!(CommunicatorRelation::Unequal, world.compare(&halves));
+        halves.free();
+    }
+
+    // communicators can be freed explicitly, in the same order on all processes
+    reversed.free();
+    star.free();
+    moon.free();
 }
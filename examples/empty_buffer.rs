@@ -0,0 +1,61 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use mpi::collective::SystemOperation;
+use mpi::point_to_point as p2p;
+use mpi::request::WaitGuard;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_process = world.process_at_rank((rank + size - 1) % size);
+
+    let empty: Vec<f64> = Vec::new();
+
+    // point to point with a receive into an empty slice
+    let mut received: Vec<f64> = Vec::new();
+    let status = p2p::send_receive_into(
+        &empty[..],
+        &next_process,
+        &mut received[..],
+        &previous_process,
+    );
+    assert_eq!(0, status.count(f64::equivalent_datatype()));
+
+    // point to point with a receive into a Vec
+    mpi::request::scope(|scope| {
+        let _sreq = WaitGuard::from(next_process.immediate_send(scope, &empty[..]));
+        let (received, status) = previous_process.receive_vec::<f64>();
+        assert!(received.is_empty());
+        assert_eq!(previous_process.rank(), status.source_rank());
+    });
+
+    // collectives
+    let mut buf: Vec<u32> = Vec::new();
+    world.process_at_rank(0).broadcast_into(&mut buf[..]);
+    assert!(buf.is_empty());
+
+    let mut gathered: Vec<u32> = Vec::new();
+    world.all_gather_into(&buf[..], &mut gathered[..]);
+    assert!(gathered.is_empty());
+
+    let mut sum: Vec<u32> = Vec::new();
+    world.all_reduce_into(&buf[..], &mut sum[..], SystemOperation::sum());
+    assert!(sum.is_empty());
+
+    // buffers of zero-sized types are rejected before any communication takes place
+    let mut zero_sized = [[0u32; 0]; 4];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        world.process_at_rank(0).broadcast_into(&mut zero_sized[..]);
+    }));
+    let message = result.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.contains("zero-sized type"));
+}
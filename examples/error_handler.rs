@@ -0,0 +1,71 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::environment::{self, ErrorHandler};
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+fn send_to_rank<C: Communicator>(comm: &C, rank: Rank) -> Result<(), mpi::Error> {
+    let x = 0i32;
+    let code = unsafe {
+        mpi::ffi::MPI_Send(
+            &x as *const i32 as *const _,
+            1,
+            i32::equivalent_datatype().as_raw(),
+            rank,
+            0,
+            comm.as_raw(),
+        )
+    };
+    if code == 0 {
+        Ok(())
+    } else {
+        Err(mpi::Error::from_code(code))
+    }
+}
+
+fn send_to_invalid_rank<C: Communicator>(comm: &C) -> Result<(), mpi::Error> {
+    // sending to a rank outside of the communicator is an error
+    send_to_rank(comm, comm.size())?;
+    Ok(())
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // only functions that report errors are used until the fatal handler is restored
+    unsafe {
+        world.set_error_handler(ErrorHandler::Return);
+    }
+
+    let error = send_to_invalid_rank(&world).unwrap_err();
+    assert_ne!(0, error.code());
+    assert_eq!(environment::error_class(error.code()), error.class());
+    assert_eq!(
+        environment::error_string(error.code()).unwrap(),
+        error.message()
+    );
+    println!("Sending to an invalid rank failed: {}", error);
+
+    // all processes agree on the outcome of a checked collective
+    let mut sum = 0;
+    world
+        .all_reduce_checked(&world.rank(), &mut sum, SystemOperation::sum())
+        .unwrap();
+    assert_eq!(world.size() * (world.size() - 1) / 2, sum);
+
+    // bitwise operations are not defined for floating point numbers, every process fails
+    let mut x = 0.0f64;
+    let error = world
+        .all_reduce_checked(&1.5f64, &mut x, SystemOperation::bitwise_and())
+        .unwrap_err();
+    assert_eq!(0, error.rank());
+    assert_ne!(0, error.error().code());
+    println!("Reduction failed: {}", error);
+
+    unsafe {
+        world.set_error_handler(ErrorHandler::Fatal);
+    }
+}
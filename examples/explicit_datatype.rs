@@ -0,0 +1,42 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::UserDatatype;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+
+    if rank == root_rank {
+        // raw bytes holding the `i32`s 0 to 5, e.g. read from a file
+        let bytes = (0..6i32)
+            .flat_map(|x| x.to_ne_bytes().to_vec())
+            .collect::<Vec<u8>>();
+
+        // every other `i32`
+        let every_other = UserDatatype::vector(3, 1, 2, &i32::equivalent_datatype());
+        for destination in 1..size {
+            unsafe {
+                world
+                    .process_at_rank(destination)
+                    .send_with_datatype(&bytes[..], 1, &every_other);
+            }
+        }
+    } else {
+        let mut received = [0i32; 3];
+        let status = unsafe {
+            world.process_at_rank(root_rank).receive_into_with_datatype(
+                &mut received[..],
+                3,
+                &i32::equivalent_datatype(),
+            )
+        };
+        assert_eq!(3, status.count_of::<i32>());
+        assert_eq!([0, 2, 4], received);
+    }
+}
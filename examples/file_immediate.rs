@@ -0,0 +1,59 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::io::{File, Offset};
+use mpi::request::WaitGuard;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let path = std::env::temp_dir().join("rsmpi_file_immediate.dat");
+    let element_size = std::mem::size_of::<u64>() as Offset;
+
+    // every process writes a few small records, interleaved with the other processes
+    let records = (0..4u64)
+        .map(|i| i * size as u64 + rank as u64)
+        .collect::<Vec<_>>();
+
+    {
+        let file = File::create(&world, &path).unwrap();
+        mpi::request::scope(|scope| {
+            let requests = records
+                .iter()
+                .map(|record| {
+                    let offset = *record as Offset * element_size;
+                    file.immediate_write_at(scope, offset, record).unwrap()
+                })
+                .collect::<Vec<_>>();
+            for request in requests {
+                request.wait();
+            }
+        });
+    }
+    world.barrier();
+
+    {
+        let file = File::open(&world, &path).unwrap();
+        let mut read = vec![0u64; records.len()];
+        mpi::request::scope(|scope| {
+            let _guards = read
+                .iter_mut()
+                .zip(records.iter())
+                .map(|(buf, record)| {
+                    let offset = *record as Offset * element_size;
+                    WaitGuard::from(file.immediate_read_at(scope, offset, buf).unwrap())
+                })
+                .collect::<Vec<_>>();
+        });
+        assert_eq!(records, read);
+    }
+
+    world.barrier();
+    if rank == 0 {
+        std::fs::remove_file(&path).unwrap();
+    }
+}
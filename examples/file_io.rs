@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::io::{self, File, Offset};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let path = std::env::temp_dir().join("rsmpi_file_io.dat");
+
+    // every process contributes a different number of values
+    let local = (0..=rank)
+        .map(|i| f64::from(rank * 100 + i))
+        .collect::<Vec<_>>();
+    io::write_concatenated(&world, &path, &local[..]).unwrap();
+
+    let global = (0..size)
+        .flat_map(|r| (0..=r).map(move |i| f64::from(r * 100 + i)))
+        .collect::<Vec<_>>();
+
+    // read back, split evenly
+    let part = io::read_distributed::<_, _, f64>(&world, &path).unwrap();
+    let len = global.len() / size as usize;
+    let rem = global.len() % size as usize;
+    let rank = rank as usize;
+    let start = rank * len + rank.min(rem);
+    let end = start + len + if rank < rem { 1 } else { 0 };
+    assert_eq!(&global[start..end], &part[..]);
+
+    {
+        let file = File::open(&world, &path).unwrap();
+        let element_size = std::mem::size_of::<f64>() as Offset;
+        assert_eq!(global.len() as Offset * element_size, file.size().unwrap());
+
+        // every process reads the last value, one more than is left in the file
+        let mut last = [0.0f64; 2];
+        let status = file
+            .read_at(element_size * (global.len() - 1) as Offset, &mut last[..])
+            .unwrap();
+        assert_eq!(1, status.count_of::<f64>());
+        assert_eq!(global[global.len() - 1], last[0]);
+    }
+
+    world.barrier();
+    if rank == 0 {
+        std::fs::remove_file(&path).unwrap();
+    }
+    world.barrier();
+
+    assert!(File::open(&world, &path).is_err());
+}
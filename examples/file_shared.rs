@@ -0,0 +1,63 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::io::{File, Offset};
+use mpi::traits::*;
+
+const LINE_LEN: usize = 16;
+
+fn log_line(rank: i32, round: i32) -> [u8; LINE_LEN] {
+    let mut line = [b' '; LINE_LEN];
+    let text = format!("rank {} round {}", rank, round);
+    line[..text.len()].copy_from_slice(text.as_bytes());
+    line[LINE_LEN - 1] = b'\n';
+    line
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let path = std::env::temp_dir().join("rsmpi_file_shared.log");
+
+    {
+        let file = File::create(&world, &path).unwrap();
+        assert_eq!(0, file.shared_position().unwrap());
+
+        // every process appends a line in each round, in rank order
+        for round in 0..2 {
+            file.write_ordered(&log_line(rank, round)[..]).unwrap();
+        }
+
+        // additional lines are appended in no particular order
+        file.write_shared(&log_line(rank, 2)[..]).unwrap();
+        world.barrier();
+        assert_eq!(
+            (3 * LINE_LEN * size as usize) as Offset,
+            file.shared_position().unwrap()
+        );
+    }
+
+    world.barrier();
+    if rank == 0 {
+        let contents = std::fs::read(&path).unwrap();
+        let lines = contents.chunks(LINE_LEN).collect::<Vec<_>>();
+        assert_eq!(3 * size as usize, lines.len());
+        for round in 0..2 {
+            for r in 0..size {
+                assert_eq!(&log_line(r, round)[..], lines[(round * size + r) as usize]);
+            }
+        }
+        let mut unordered = lines[2 * size as usize..].to_vec();
+        unordered.sort();
+        let mut expected = (0..size).map(|r| log_line(r, 2)).collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(
+            expected.iter().map(|l| &l[..]).collect::<Vec<_>>(),
+            unordered
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
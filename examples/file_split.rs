@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::io::{File, Offset};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let path = std::env::temp_dir().join("rsmpi_file_split.dat");
+
+    let count = 4;
+    let offset = (rank * count) as Offset * std::mem::size_of::<i32>() as Offset;
+    let local = (0..count).map(|i| rank * count + i).collect::<Vec<_>>();
+
+    {
+        let mut file = File::create(&world, &path).unwrap();
+        mpi::request::scope(|scope| {
+            let write = file.write_at_all_begin(scope, offset, &local[..]).unwrap();
+            // do other work while the data is written
+            let checksum: i32 = local.iter().sum();
+            let status = write.end().unwrap();
+            assert_eq!(count as usize, status.count_of::<i32>());
+            assert_eq!(count * (2 * rank * count + count - 1) / 2, checksum);
+        });
+    }
+
+    {
+        // read the part of the next process
+        let next_rank = (rank + 1) % size;
+        let next_offset = (next_rank * count) as Offset * std::mem::size_of::<i32>() as Offset;
+        let mut next = vec![-1; count as usize];
+        let mut file = File::open(&world, &path).unwrap();
+        mpi::request::scope(|scope| {
+            let read = file
+                .read_at_all_begin(scope, next_offset, &mut next[..])
+                .unwrap();
+            read.end().unwrap();
+        });
+        assert_eq!(
+            (0..count)
+                .map(|i| next_rank * count + i)
+                .collect::<Vec<_>>(),
+            next
+        );
+    }
+
+    world.barrier();
+    if rank == 0 {
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::mem::size_of;
+
+use mpi::datatype::fortran;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+
+    // MPI may have been built without Fortran support.
+    let (integer, double_precision) = match (fortran::integer(), fortran::double_precision()) {
+        (Some(integer), Some(double_precision)) => (integer, double_precision),
+        _ => return,
+    };
+
+    // The size of Fortran types depends on the Fortran compiler, only use `i32` if they agree.
+    if integer.get_extent().1 != size_of::<i32>() as mpi::Address {
+        return;
+    }
+    assert_eq!(
+        size_of::<f64>() as mpi::Address,
+        double_precision.get_extent().1
+    );
+
+    if rank == root_rank {
+        let values = [1i32, 2, 3, 4];
+        for destination in 1..size {
+            unsafe {
+                world
+                    .process_at_rank(destination)
+                    .send_with_datatype(&values[..], 4, &integer);
+            }
+        }
+    } else {
+        let mut received = [0i32; 4];
+        let status = unsafe {
+            world.process_at_rank(root_rank).receive_into_with_datatype(
+                &mut received[..],
+                4,
+                &integer,
+            )
+        };
+        assert_eq!(4, status.count(&integer));
+        assert_eq!([1, 2, 3, 4], received);
+    }
+}
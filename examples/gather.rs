@@ -1,6 +1,8 @@
 #![deny(warnings)]
 extern crate mpi;
 
+use std::panic::{self, AssertUnwindSafe};
+
 use mpi::datatype::{MutView, UserDatatype, View};
 use mpi::traits::*;
 use mpi::Count;
@@ -46,6 +48,36 @@ fn main() {
         root_process.gather_into(&a[..]);
     }
 
+    if let Some(t) = root_process.gather(&a[..]) {
+        assert_eq!(world.rank(), root_rank);
+        assert_eq!(count * count, t.len());
+        assert!((0_u64..)
+            .zip(t.iter())
+            .all(|(a, &b)| b == (a / count as u64 + 1) * (a % count as u64 + 1)));
+    } else {
+        assert_ne!(world.rank(), root_rank);
+    }
+
+    // a root buffer of the wrong length is rejected before any communication takes place
+    if world.rank() == root_rank {
+        let mut short = vec![0u64; count * count - 1];
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            root_process.gather_into_root(&a[..], &mut short[..]);
+        }));
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(message.contains(&format!(
+            "got {} expected {}",
+            count * count - 1,
+            count * count
+        )));
+
+        let mut t = vec![0u64; count * count];
+        root_process.gather_into_root(&a[..], &mut t[..]);
+    } else {
+        root_process.gather_into(&a[..]);
+    }
+
     let d = UserDatatype::contiguous(count as Count, &u64::equivalent_datatype());
     let sv = unsafe { View::with_count_and_datatype(&a[..], 1, &d) };
 
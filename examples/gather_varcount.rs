@@ -1,6 +1,8 @@
 #![deny(warnings)]
 extern crate mpi;
 
+use std::mem::MaybeUninit;
+
 use mpi::datatype::PartitionMut;
 use mpi::traits::*;
 use mpi::Count;
@@ -42,4 +44,20 @@ fn main() {
     } else {
         root_process.gather_varcount_into(&msg[..]);
     }
+
+    // gather into uninitialized memory without zeroing it first
+    if rank == root_rank {
+        let counts: Vec<Count> = (0..size).collect();
+        let mut buf = vec![MaybeUninit::uninit(); (size * (size - 1) / 2) as usize];
+        // every process sends exactly `rank` elements, as described by `counts`
+        let contributions = unsafe {
+            root_process.gather_varcount_into_root_uninit(&msg[..], &mut buf[..], &counts)
+        };
+        assert_eq!(size as usize, contributions.len());
+        for (r, contribution) in contributions.iter().enumerate() {
+            assert_eq!(&(0..r as Count).collect::<Vec<_>>()[..], &contribution[..]);
+        }
+    } else {
+        root_process.gather_varcount_into(&msg[..]);
+    }
 }
@@ -0,0 +1,34 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank + size - 1) % size;
+
+    // three `i32`s fill one and a half pairs of `i32`
+    let msg = [1i32, 2, 3];
+    let mut buf = [[0i32; 2]; 2];
+
+    mpi::request::scope(|scope| {
+        let sreq = world
+            .process_at_rank(next_rank)
+            .immediate_send(scope, &msg[..]);
+        let status = world
+            .process_at_rank(previous_rank)
+            .receive_into(&mut buf[..]);
+        sreq.wait();
+
+        assert_eq!(None, status.count_as::<[i32; 2]>());
+        assert_eq!(3, status.elements::<[i32; 2]>());
+        assert_eq!(3, status.elements_x::<[i32; 2]>());
+        assert_eq!(3, status.count_of::<i32>());
+        assert_eq!([[1, 2], [3, 0]], buf);
+    });
+}
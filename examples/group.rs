@@ -71,6 +71,26 @@ fn main() {
         (f.rank().is_some() && s.rank().is_none()) ^ (f.rank().is_none() && s.rank().is_some())
     );
 
+    // ranges of ranks
+    if g.size() > 1 {
+        let evens = g.range_include(&[(0, g.size() - 1, 2)]);
+        let odds = g.range_exclude(&[(0, g.size() - 1, 2)]);
+        assert_eq!((g.size() + 1) / 2, evens.size());
+        assert_eq!(g.size() / 2, odds.size());
+        assert_eq!(
+            GroupRelation::Identical,
+            odds.compare(&g.difference(&evens))
+        );
+        assert_eq!(
+            Some(g.rank().unwrap() / 2),
+            if g.rank().unwrap() % 2 == 0 {
+                evens.rank()
+            } else {
+                odds.rank()
+            }
+        );
+    }
+
     // inverting rank mappings
     let rev: Vec<Rank> = (0..g.size()).rev().collect();
     let r = g.include(&rev[..]);
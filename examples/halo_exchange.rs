@@ -0,0 +1,58 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::topology::HaloExchange;
+use mpi::traits::*;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+
+    // a periodic 2D grid of blocks with 3 x 4 interior cells and ghost cells 2 cells wide
+    let dims = [size, 1];
+    let cart = world
+        .create_cartesian_communicator(&dims, &[true, true], false)
+        .unwrap();
+    let coords = cart.rank_to_coordinates(cart.rank());
+
+    let interior = [3, 4];
+    let width = 2;
+    let sizes = [interior[0] + 2 * width, interior[1] + 2 * width];
+    let global = [dims[0] * interior[0], dims[1] * interior[1]];
+
+    // the value of a cell is its global index, wrapped around the periodic boundaries
+    let value = |i: Count, j: Count| {
+        let gi = (coords[0] * interior[0] + i - width).rem_euclid(global[0]);
+        let gj = (coords[1] * interior[1] + j - width).rem_euclid(global[1]);
+        gi * global[1] + gj
+    };
+    let is_ghost = |i: Count, j: Count| {
+        i < width || i >= sizes[0] - width || j < width || j >= sizes[1] - width
+    };
+
+    let mut block = Vec::new();
+    for i in 0..sizes[0] {
+        for j in 0..sizes[1] {
+            block.push(if is_ghost(i, j) { -1 } else { value(i, j) });
+        }
+    }
+
+    let halo = HaloExchange::new(&cart, &sizes, width);
+    halo.exchange(&mut block[..]);
+
+    // all ghost cells, including the corners, now hold the values of the neighbouring blocks
+    for i in 0..sizes[0] {
+        for j in 0..sizes[1] {
+            assert_eq!(value(i, j), block[(i * sizes[1] + j) as usize]);
+        }
+    }
+
+    // exchanging again does not change anything
+    halo.exchange(&mut block[..]);
+    assert!(block
+        .iter()
+        .enumerate()
+        .all(|(k, &x)| x == value(k as Count / sizes[1], k as Count % sizes[1])));
+}
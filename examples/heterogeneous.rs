@@ -0,0 +1,86 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{address_of, UserDatatype, View};
+use mpi::topology::Rank;
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::size_of;
+
+#[repr(C)]
+struct Particle {
+    id: Rank,
+    mass: f64,
+    position: [f64; 3],
+}
+
+const PARTICLES: usize = 4;
+
+fn mass(rank: Rank, i: usize) -> f64 {
+    f64::from(rank) * 10.0 + i as f64
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let particles = (0..PARTICLES)
+        .map(|i| Particle {
+            id: rank,
+            mass: mass(rank, i),
+            position: [f64::from(rank); 3],
+        })
+        .collect::<Vec<_>>();
+    assert!(particles
+        .iter()
+        .all(|p| p.id == rank && p.position[0] == f64::from(rank)));
+
+    // The `mass` fields are scattered through the array of structs, `size_of::<Particle>()` bytes
+    // apart. The byte stride describes this directly, without converting the distance into a
+    // number of `f64`s.
+    let offset = address_of(&particles[0].mass) - address_of(&particles[0]);
+    let masses = UserDatatype::heterogeneous_vector(
+        PARTICLES as _,
+        1,
+        size_of::<Particle>() as Address,
+        &f64::equivalent_datatype(),
+    );
+    let masses = UserDatatype::structured(&[1], &[offset], &[masses]);
+
+    // Every other particle, selected through byte displacements relative to the first one
+    let even_displacements = (0..PARTICLES)
+        .step_by(2)
+        .map(|i| address_of(&particles[i].mass) - address_of(&particles[0]))
+        .collect::<Vec<_>>();
+    let even_masses = UserDatatype::heterogeneous_indexed(
+        &vec![1; even_displacements.len()],
+        &even_displacements,
+        &f64::equivalent_datatype(),
+    );
+
+    let v = unsafe { View::with_count_and_datatype(&particles[..], 1, &masses) };
+    let w = unsafe { View::with_count_and_datatype(&particles[..], 1, &even_masses) };
+    if rank == root_rank {
+        let mut gathered = vec![0.0f64; PARTICLES * size as usize];
+        root_process.gather_into_root(&v, &mut gathered[..]);
+        let expected = (0..size)
+            .flat_map(|r| (0..PARTICLES).map(move |i| mass(r, i)))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, gathered);
+
+        let mut gathered = vec![0.0f64; even_displacements.len() * size as usize];
+        root_process.gather_into_root(&w, &mut gathered[..]);
+        let expected = (0..size)
+            .flat_map(|r| (0..PARTICLES).step_by(2).map(move |i| mass(r, i)))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, gathered);
+    } else {
+        root_process.gather_into(&v);
+        root_process.gather_into(&w);
+    }
+}
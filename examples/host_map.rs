@@ -0,0 +1,38 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::collections::BTreeMap;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let hosts = world.gather_host_map();
+    assert_eq!(world.size() as usize, hosts.len());
+    assert_eq!(
+        mpi::environment::processor_name().unwrap(),
+        hosts[rank as usize]
+    );
+
+    // group the ranks by the node they are running on
+    let mut nodes = BTreeMap::new();
+    for (r, host) in hosts.iter().enumerate() {
+        nodes.entry(host.as_str()).or_insert_with(Vec::new).push(r);
+    }
+    if rank == 0 {
+        for (host, ranks) in &nodes {
+            println!("{}: {:?}", host, ranks);
+        }
+    }
+    assert!(nodes[hosts[rank as usize].as_str()].contains(&(rank as usize)));
+
+    // processes on the same host share memory, so the node-local ranks follow the host map
+    let node_rank = world.node_rank();
+    let node_count = world.node_count();
+    assert_eq!(nodes.len(), node_count as usize);
+    let local_ranks = &nodes[hosts[rank as usize].as_str()];
+    assert_eq!(rank as usize, local_ranks[node_rank as usize]);
+}
@@ -52,6 +52,16 @@ fn main() {
     });
     assert_eq!(x, y);
 
+    // the receive outlives the scope it was started in, `y` is not touched until it completes
+    y = 0.0;
+    let rreq = mpi::request::scope(|scope| {
+        let rreq = world.any_process().immediate_receive_into(scope, &mut y);
+        unsafe { rreq.promote_to_static() }
+    });
+    world.this_process().send(&x);
+    rreq.wait();
+    assert_eq!(x, y);
+
     let future = world.any_process().immediate_receive();
     world.this_process().send(&x);
     let (msg, _) = future.get();
@@ -74,6 +84,24 @@ fn main() {
         }
     }
 
+    // nudge the MPI library during a computation that does not test the requests
+    y = 0.0;
+    mpi::request::scope(|scope| {
+        let rreq = world.any_process().immediate_receive_into(scope, &mut y);
+        let sreq = world.this_process().immediate_send(scope, &x);
+        let mut sum = 0.0_f32;
+        for i in 0..1000 {
+            sum += (i as f32).sqrt();
+            if i % 100 == 0 {
+                world.progress();
+            }
+        }
+        assert!(sum > 0.0);
+        rreq.wait();
+        sreq.wait();
+    });
+    assert_eq!(x, y);
+
     mpi::request::scope(|scope| {
         let sreq = world.this_process().immediate_send(scope, &x);
         sreq.cancel();
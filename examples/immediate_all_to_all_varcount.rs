@@ -0,0 +1,54 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Partition, PartitionMut};
+use mpi::traits::*;
+use mpi::Count;
+
+fn displacements(counts: &[Count]) -> Vec<Count> {
+    counts
+        .iter()
+        .scan(0, |acc, &x| {
+            let tmp = *acc;
+            *acc += x;
+            Some(tmp)
+        })
+        .collect()
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // every process sends `d + 1` copies of its rank to process `d`
+    let send_counts: Vec<Count> = (1..=size).collect();
+    let send_displs = displacements(&send_counts);
+    let msg: Vec<_> = send_counts
+        .iter()
+        .flat_map(|&count| std::iter::repeat(rank).take(count as usize))
+        .collect();
+
+    // and thus receives `rank + 1` elements from every process
+    let recv_counts: Vec<Count> = vec![rank + 1; size as usize];
+    let recv_displs = displacements(&recv_counts);
+    let mut buf = vec![-1; (size * (rank + 1)) as usize];
+
+    {
+        // the counts and displacements stay borrowed until the request has completed
+        let partition = Partition::new(&msg[..], &send_counts[..], &send_displs[..]);
+        let mut partition_mut = PartitionMut::new(&mut buf[..], &recv_counts[..], &recv_displs[..]);
+        mpi::request::scope(|scope| {
+            let request =
+                world.immediate_all_to_all_varcount_into(scope, &partition, &mut partition_mut);
+            // overlap local work with the exchange here
+            request.wait();
+        });
+    }
+
+    let expected: Vec<_> = (0..size)
+        .flat_map(|r| std::iter::repeat(r).take(rank as usize + 1))
+        .collect();
+    assert_eq!(expected, buf);
+}
@@ -0,0 +1,39 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank + size - 1) % size;
+
+    let msg = vec![rank; 4];
+    let buf = vec![-1; 4];
+
+    // the requests own their buffers and are not tied to a scope
+    let mut requests = vec![
+        world
+            .process_at_rank(previous_rank)
+            .immediate_receive_owned(buf),
+        world.process_at_rank(next_rank).immediate_send_owned(msg),
+    ];
+
+    // poll the send request until it has completed
+    let mut send = requests.pop().unwrap();
+    let msg = loop {
+        match send.test() {
+            Ok((msg, _)) => break msg,
+            Err(request) => send = request,
+        }
+    };
+    assert_eq!(vec![rank; 4], msg);
+
+    let (buf, status) = requests.pop().unwrap().wait();
+    assert_eq!(previous_rank, status.source_rank());
+    assert_eq!(vec![previous_rank; 4], buf);
+}
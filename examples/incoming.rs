@@ -0,0 +1,62 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+const MESSAGES: i32 = 3;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+
+    if rank == root_rank {
+        // every other process sends some messages followed by an empty sentinel message
+        let mut finished = 0;
+        let mut received = 0;
+        if size > 1 {
+            for (msg, status) in world.any_process().incoming_with_tag::<i32>(1) {
+                if msg.is_empty() {
+                    finished += 1;
+                    if finished == size - 1 {
+                        break;
+                    }
+                } else {
+                    assert_eq!(vec![status.source_rank(); msg.len()], msg);
+                    received += 1;
+                }
+            }
+        }
+        assert_eq!((size - 1) * MESSAGES, received);
+    } else {
+        let process = world.process_at_rank(root_rank);
+        for i in 1..=MESSAGES {
+            process.send_with_tag(&vec![rank; i as usize][..], 1);
+        }
+        let sentinel: [i32; 0] = [];
+        process.send_with_tag(&sentinel[..], 1);
+    }
+
+    // drain messages that have already arrived without blocking
+    let process = world.this_process();
+    let mut drained = 0;
+    mpi::request::scope(|scope| {
+        let requests = (0..MESSAGES)
+            .map(|i| process.immediate_send_with_tag(scope, &rank, 2 + i))
+            .collect::<Vec<_>>();
+        while drained < MESSAGES {
+            for (msg, status) in process.try_incoming::<i32>() {
+                assert_eq!(vec![rank], msg);
+                assert_eq!(2 + drained, status.tag());
+                drained += 1;
+            }
+        }
+        for request in requests {
+            request.wait();
+        }
+    });
+    assert_eq!(0, process.try_incoming::<i32>().count());
+}
@@ -0,0 +1,39 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::info::Info;
+use mpi::topology::CommunicatorRelation;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let mut info = Info::new();
+    assert_eq!(0, info.num_keys());
+    assert_eq!(None, info.get("mpi_assert_no_any_tag"));
+
+    info.set("mpi_assert_no_any_tag", "true");
+    assert_eq!(Some("true".to_string()), info.get("mpi_assert_no_any_tag"));
+    assert_eq!(1, info.num_keys());
+
+    info.set("mpi_assert_no_any_tag", "false");
+    assert_eq!(Some("false".to_string()), info.get("mpi_assert_no_any_tag"));
+
+    let copy = info.clone();
+    info.delete("mpi_assert_no_any_tag");
+    assert_eq!(None, info.get("mpi_assert_no_any_tag"));
+    assert_eq!(Some("false".to_string()), copy.get("mpi_assert_no_any_tag"));
+
+    let info: Info = vec![
+        ("mpi_assert_no_any_tag", "true"),
+        ("mpi_assert_no_any_source", "true"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(2, info.num_keys());
+
+    let comm = world.duplicate_with_info(&info);
+    assert_eq!(CommunicatorRelation::Congruent, world.compare(&comm));
+    comm.barrier();
+}
@@ -0,0 +1,22 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::environment;
+
+fn main() {
+    assert!(!environment::is_initialized());
+
+    let universe = mpi::initialize().unwrap();
+    assert!(environment::is_initialized());
+    assert!(!environment::is_finalized());
+
+    // MPI is already initialized
+    assert!(mpi::initialize().is_none());
+
+    drop(universe);
+    assert!(environment::is_initialized());
+    assert!(environment::is_finalized());
+
+    // MPI cannot be initialized again after it has been finalized
+    assert!(mpi::initialize().is_none());
+}
@@ -4,7 +4,9 @@ extern crate mpi;
 use mpi::Threading;
 
 fn main() {
-    let (_universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
     assert_eq!(threading, mpi::environment::threading_support());
+    assert_eq!(threading, universe.threading_support());
+    assert!(Threading::Single <= threading);
     println!("Supported level of threading: {:?}", threading);
 }
@@ -0,0 +1,52 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::topology::{Color, GroupRelation};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // split the world into the even and the odd processes
+    let is_odd = rank % 2 != 0;
+    let local = world
+        .split_by_color(Color::with_value(rank % 2))
+        .expect("Every process should join a communicator.");
+
+    // the leaders are world rank 0 for the even and world rank 1 for the odd processes
+    let remote_leader = if is_odd { 0 } else { 1 };
+    let inter = local.create_intercommunicator(0, &world, remote_leader);
+
+    assert_eq!(local.size(), inter.size());
+    assert_eq!(local.rank(), inter.rank());
+    assert_eq!(size - local.size(), inter.remote_size());
+    assert_eq!(inter.remote_size(), inter.remote_group().size());
+
+    // point to point communication addresses processes in the remote group
+    if inter.rank() == 0 {
+        let partner = inter.process_at_rank(0);
+        if is_odd {
+            let (msg, _) = partner.receive::<i32>();
+            assert_eq!(0, msg);
+        } else {
+            partner.send(&rank);
+        }
+    }
+
+    // merging puts the even processes first
+    let merged = inter.merge(is_odd);
+    assert_eq!(size, merged.size());
+    let expected_rank = if is_odd {
+        (size + 1) / 2 + rank / 2
+    } else {
+        rank / 2
+    };
+    assert_eq!(expected_rank, merged.rank());
+    assert_ne!(
+        GroupRelation::Unequal,
+        world.group().compare(&merged.group())
+    );
+}
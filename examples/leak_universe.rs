@@ -0,0 +1,21 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::environment;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap().leak();
+    let world = universe.world();
+
+    let mut x = world.rank();
+    world.process_at_rank(0).broadcast(&mut x);
+    assert_eq!(0, x);
+
+    // the host application is responsible for finalizing MPI
+    assert!(!environment::is_finalized());
+    unsafe {
+        mpi::ffi::MPI_Finalize();
+    }
+    assert!(environment::is_finalized());
+}
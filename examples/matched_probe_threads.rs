@@ -0,0 +1,49 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::sync::mpsc;
+use std::thread;
+
+use mpi::point_to_point::Message;
+use mpi::topology::Rank;
+use mpi::traits::*;
+use mpi::Threading;
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    if threading != Threading::Multiple {
+        println!("Skipping, MPI does not support Threading::Multiple.");
+        return;
+    }
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    if rank != 0 {
+        world.process_at_rank(0).send(&rank);
+        return;
+    }
+
+    // a worker thread receives the messages that the main thread has matched
+    let (sender, receiver) = mpsc::channel::<Message>();
+    let worker = thread::spawn(move || {
+        receiver
+            .into_iter()
+            .map(|message| message.matched_receive::<Rank>().0)
+            .collect::<Vec<_>>()
+    });
+
+    let mut matched = 0;
+    while matched < size - 1 {
+        if let Some((message, status)) = world.any_process().immediate_matched_probe() {
+            println!("Matched a message from rank {}.", status.source_rank());
+            sender.send(message).unwrap();
+            matched += 1;
+        }
+    }
+    drop(sender);
+
+    let mut received = worker.join().unwrap();
+    received.sort_unstable();
+    assert_eq!((1..size).collect::<Vec<_>>(), received);
+}
@@ -0,0 +1,38 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::traits::*;
+use ndarray::{Array1, Array2};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size() as usize;
+
+    let root_process = world.process_at_rank(0);
+
+    let mut grid = if rank == 0 {
+        Array2::from_shape_fn((3, 4), |(i, j)| (4 * i + j) as f64)
+    } else {
+        Array2::zeros((3, 4))
+    };
+    root_process.broadcast_into(&mut grid.view_mut());
+    assert_eq!(
+        Array2::from_shape_fn((3, 4), |(i, j)| (4 * i + j) as f64),
+        grid
+    );
+
+    // every process contributes one row of the gathered matrix
+    let row = Array1::from_elem(4, rank);
+    let mut rows = Array2::zeros((size, 4));
+    world.all_gather_into(&row.view(), &mut rows.view_mut());
+    for (i, row) in rows.outer_iter().enumerate() {
+        assert!(row.iter().all(|&x| x == i as mpi::topology::Rank));
+    }
+
+    let mut sum = Array2::<f64>::zeros((3, 4));
+    world.all_reduce_into(&grid, &mut sum, SystemOperation::sum());
+    assert_eq!(grid * size as f64, sum);
+}
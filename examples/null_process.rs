@@ -0,0 +1,54 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::point_to_point as p2p;
+use mpi::topology;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    // a non-periodic chain of processes, the ends have no neighbours
+    let right = if rank + 1 < size {
+        world.process_at_rank(rank + 1)
+    } else {
+        world.null_process()
+    };
+    let left = if rank > 0 {
+        world.process_at_rank(rank - 1)
+    } else {
+        world.null_process()
+    };
+
+    // shift values to the right without special cases at the boundaries
+    let mut halo = -1;
+    let status = p2p::send_receive_into(&rank, &right, &mut halo, &left);
+    println!("Rank {} received {}, status: {:?}.", rank, halo, status);
+
+    if left.is_null() {
+        assert_eq!(-1, halo);
+        assert_eq!(world.null_process().rank(), status.source_rank());
+        assert_eq!(0, status.count_of::<i32>());
+    } else {
+        assert_eq!(rank - 1, halo);
+        assert_eq!(rank - 1, status.source_rank());
+    }
+
+    // sending to and receiving from the null process directly are no-ops as well
+    world.null_process().send(&rank);
+    let mut x = 42;
+    world.null_process().receive_into(&mut x);
+    assert_eq!(42, x);
+
+    // the sentinel ranks and tags are available without reaching into the raw constants
+    assert_eq!(topology::proc_null(), world.null_process().rank());
+    assert_ne!(topology::proc_null(), topology::any_source());
+    let status = world
+        .null_process()
+        .receive_into_with_tag(&mut x, p2p::any_tag());
+    assert_eq!(topology::proc_null(), status.source_rank());
+    assert_eq!(p2p::any_tag(), status.tag());
+}
@@ -0,0 +1,51 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::Optional;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank + size - 1) % size;
+
+    // only even ranks have something to say
+    let msg: Optional<i32> = if rank % 2 == 0 { Some(rank) } else { None }.into();
+
+    let (received, _) = mpi::point_to_point::send_receive::<Optional<i32>, _, _, _>(
+        &msg,
+        &world.process_at_rank(next_rank),
+        &world.process_at_rank(previous_rank),
+    );
+    let expected = if previous_rank % 2 == 0 {
+        Some(previous_rank)
+    } else {
+        None
+    };
+    assert_eq!(expected, received.into_option());
+
+    // slices of optional values of a larger type
+    let values: Vec<Optional<f64>> = (0..size)
+        .map(|r| {
+            if r == rank {
+                Optional::none()
+            } else {
+                Optional::some(f64::from(r))
+            }
+        })
+        .collect();
+    let mut gathered: Vec<Optional<f64>> = (0..size * size).map(|_| Optional::none()).collect();
+    world.all_gather_into(&values[..], &mut gathered[..]);
+    for (i, value) in gathered.iter().enumerate() {
+        let (r, j) = (i as i32 / size, i as i32 % size);
+        if r == j {
+            assert_eq!(None, value.as_option());
+        } else {
+            assert_eq!(Some(&f64::from(j)), value.as_option());
+        }
+    }
+}
@@ -0,0 +1,50 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Packer, Unpacker};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let ints = [3i32, 2, 1];
+    let floats = [0.5f64, 1.5];
+
+    let capacity = world.packed_size(&ints[..]) + world.packed_size(&floats[..]);
+    let mut packed = vec![0u8; capacity];
+
+    let position = world.pack_into(&ints[..], &mut packed[..], 0);
+    let position = world.pack_into(&floats[..], &mut packed[..], position);
+    assert!(position as usize <= capacity);
+
+    let mut new_ints = [0, 0, 0];
+    let mut new_floats = [0.0, 0.0];
+    unsafe {
+        let position = world.unpack_into(&packed, &mut new_ints[..], 0);
+        world.unpack_into(&packed, &mut new_floats[..], position);
+    }
+
+    assert_eq!([3, 2, 1], new_ints);
+    assert_eq!([0.5, 1.5], new_floats);
+
+    // a header followed by a variable-length array, packed into one message
+    let step = 42u64;
+    let samples: Vec<f64> = (0..world.rank() + 3).map(f64::from).collect();
+    let mut packer = Packer::new(&world);
+    packer
+        .pack(&step)
+        .pack_slice(&samples[..])
+        .pack(&floats[..]);
+    let message = packer.finish();
+
+    let mut unpacker = Unpacker::new(&world, &message[..]);
+    let mut new_floats = [0.0, 0.0];
+    unsafe {
+        assert_eq!(step, unpacker.unpack::<u64>());
+        assert_eq!(samples, unpacker.unpack_vec::<f64>());
+        unpacker.unpack_into(&mut new_floats[..]);
+    }
+    assert_eq!(floats, new_floats);
+    assert_eq!(0, unpacker.remaining());
+}
@@ -0,0 +1,45 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let mut local = [0.0f64; 2];
+    let mut global = [0.0f64; 2];
+
+    let mut sums = Vec::new();
+    mpi::request::scope(|scope| {
+        // the reduction is set up once and started in every iteration
+        let mut request = world.all_reduce_init(
+            scope,
+            &mut local[..],
+            &mut global[..],
+            SystemOperation::sum(),
+        );
+
+        for step in 0..10 {
+            {
+                let (local, _) = request.buffers_mut();
+                local[0] = f64::from(rank) * f64::from(step);
+                local[1] = 1.0;
+            }
+            request.start();
+            request.wait();
+            sums.push(request.buffers().1[0]);
+        }
+
+        let (_, global) = request.free();
+        assert_eq!(f64::from(size), global[1]);
+    });
+
+    let ranks = f64::from(size * (size - 1) / 2);
+    for (step, &sum) in sums.iter().enumerate() {
+        assert_eq!(ranks * step as f64, sum);
+    }
+}
@@ -0,0 +1,45 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::time::Duration;
+
+use mpi::request::{ProgressEngine, StaticScope};
+use mpi::traits::*;
+use mpi::Threading;
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    if threading != Threading::Multiple {
+        println!("Skipping, MPI does not support Threading::Multiple.");
+        return;
+    }
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank + size - 1) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    let engine = ProgressEngine::new(&universe, Duration::from_millis(1));
+
+    // buffers of requests registered with the engine have to live until the request has finished
+    let msg: &'static [i32] = Box::leak(vec![rank; 1024].into_boxed_slice());
+    let send = engine.register(next_process.immediate_send(StaticScope, msg));
+
+    // overlap some computation with the communication
+    let local: i64 = (0..1_000_000i64).sum();
+    assert_eq!(499_999_500_000, local);
+
+    let (received, _) = previous_process.receive_vec::<i32>();
+    assert_eq!(vec![previous_rank; 1024], received);
+    send.wait();
+
+    let barrier = engine.register(world.immediate_barrier());
+    let barrier = match engine.unregister(barrier) {
+        // take the request back and complete it on this thread
+        Ok(request) => request.wait(),
+        Err(status) => status,
+    };
+    println!("Rank {} passed the barrier, status: {:?}.", rank, barrier);
+}
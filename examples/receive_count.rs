@@ -0,0 +1,47 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank + size - 1) % size;
+
+    // every rank sends a message of a different length
+    let msg: Vec<u16> = (0..(rank as u16 + 1)).collect();
+
+    // the receive buffer is large enough for any of the messages
+    let mut buf = vec![u16::MAX; size as usize + 1];
+
+    mpi::request::scope(|scope| {
+        let sreq = world
+            .process_at_rank(next_rank)
+            .immediate_send(scope, &msg[..]);
+        let status = world
+            .process_at_rank(previous_rank)
+            .receive_into(&mut buf[..]);
+        sreq.wait();
+
+        // only the first `count_of` elements have been filled
+        let count = status.count_of::<u16>();
+        let received = &buf[..count];
+        println!("Rank {} received {:?}.", rank, received);
+
+        assert_eq!(previous_rank as usize + 1, count);
+        assert_eq!((0..count as u16).collect::<Vec<_>>(), received);
+        assert!(buf[count..].iter().all(|&x| x == u16::MAX));
+
+        // the same message viewed as bytes and as pairs of `u16`
+        assert_eq!(2 * count, status.byte_count());
+        if count % 2 == 0 {
+            assert_eq!(Some(count / 2), status.count_as::<[u16; 2]>());
+        } else {
+            assert_eq!(None, status.count_as::<[u16; 2]>());
+        }
+    });
+}
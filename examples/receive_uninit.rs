@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::mem::MaybeUninit;
+
+use mpi::request::WaitGuard;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank > 0 { rank - 1 } else { size - 1 };
+
+    let msg: Vec<f64> = (0..=rank).map(f64::from).collect();
+
+    mpi::request::scope(|scope| {
+        let _sreq = WaitGuard::from(
+            world
+                .process_at_rank(next_rank)
+                .immediate_send(scope, &msg[..]),
+        );
+
+        // the buffer is large enough to hold any message, but only partially filled
+        let mut buf = vec![MaybeUninit::<f64>::uninit(); size as usize];
+        let (received, status) = world
+            .process_at_rank(previous_rank)
+            .receive_into_uninit(&mut buf[..]);
+        println!(
+            "Rank {} received {:?}, status: {:?}.",
+            rank, received, status
+        );
+
+        let expected: Vec<f64> = (0..=previous_rank).map(f64::from).collect();
+        assert_eq!(&expected[..], received);
+    });
+}
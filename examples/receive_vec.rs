@@ -0,0 +1,39 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::request::WaitGuard;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank > 0 { rank - 1 } else { size - 1 };
+
+    // rank 0 sends an empty message
+    let msg: Vec<u64> = (0..rank as u64).collect();
+    let tag = 3;
+
+    mpi::request::scope(|scope| {
+        let _sreq = WaitGuard::from(world.process_at_rank(next_rank).immediate_send_with_tag(
+            scope,
+            &msg[..],
+            tag,
+        ));
+
+        let (received, status) = world
+            .process_at_rank(previous_rank)
+            .receive_vec_with_tag::<u64>(tag);
+        println!(
+            "Rank {} received {:?}, status: {:?}.",
+            rank, received, status
+        );
+
+        assert_eq!(tag, status.tag());
+        assert_eq!(previous_rank as usize, received.len());
+        assert_eq!((0..previous_rank as u64).collect::<Vec<_>>(), received);
+    });
+}
@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::point_to_point::RecvBuffer;
+use mpi::traits::*;
+
+const ROUNDS: usize = 5;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank + size - 1) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    let mut buffer = RecvBuffer::<u32>::with_capacity(ROUNDS);
+    assert_eq!(ROUNDS, buffer.capacity());
+
+    // messages shrink from round to round, only the freshly received prefix is visible
+    for round in 0..ROUNDS {
+        let len = ROUNDS - round;
+        let msg = vec![round as u32; len];
+        mpi::request::scope(|scope| {
+            let sreq = next_process.immediate_send(scope, &msg[..]);
+            let received = buffer.recv_from(&previous_process);
+            assert_eq!(&msg[..], received);
+            sreq.wait();
+        });
+    }
+
+    let empty: [u32; 0] = [];
+    mpi::request::scope(|scope| {
+        let sreq = next_process.immediate_send_with_tag(scope, &empty[..], 7);
+        assert!(buffer.recv_from_with_tag(&previous_process, 7).is_empty());
+        sreq.wait();
+    });
+}
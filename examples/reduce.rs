@@ -4,10 +4,16 @@
 extern crate mpi;
 
 use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+
+#[cfg(feature = "user-operations")]
+use std::slice;
 
 #[cfg(feature = "user-operations")]
 use mpi::collective::UserOperation;
-use mpi::collective::{self, SystemOperation, UnsafeUserOperation};
+use mpi::collective::{self, SystemOperation, UnsafeUserOperation, ValueIndex};
+#[cfg(feature = "user-operations")]
+use mpi::datatype::UserDatatype;
 use mpi::ffi::MPI_Datatype;
 use mpi::topology::Rank;
 use mpi::traits::*;
@@ -29,6 +35,42 @@ fn test_user_operations<C: Communicator>(comm: C) {
         }),
     );
     assert_eq!(h, size * (size + 1) / 2);
+
+    // reduce pairs of (sum, count) described by a committed datatype with a user operation
+    let pair = UserDatatype::contiguous(2, &Rank::equivalent_datatype());
+    let add_pairs = UserOperation::commutative(|x, mut y| {
+        let len = x.len();
+        let x = unsafe { slice::from_raw_parts(x.as_ptr() as *const [Rank; 2], len) };
+        let y = unsafe { slice::from_raw_parts_mut(y.as_mut_ptr() as *mut [Rank; 2], len) };
+        for (x_i, y_i) in x.iter().zip(y) {
+            y_i[0] += x_i[0];
+            y_i[1] += x_i[1];
+        }
+    });
+    let mut total = [0, 0];
+    unsafe {
+        comm.all_reduce_into_with_datatype(&[rank, 1][..], &mut total[..], 1, &pair, &add_pairs);
+    }
+    assert_eq!([size * (size - 1) / 2, size], total);
+
+    let root_process = comm.process_at_rank(0);
+    if rank == 0 {
+        let mut total = [0, 0];
+        unsafe {
+            root_process.reduce_into_root_with_datatype(
+                &[rank, 1][..],
+                &mut total[..],
+                1,
+                &pair,
+                &add_pairs,
+            );
+        }
+        assert_eq!([size * (size - 1) / 2, size], total);
+    } else {
+        unsafe {
+            root_process.reduce_into_with_datatype(&[rank, 1][..], 1, &pair, &add_pairs);
+        }
+    }
 }
 
 #[cfg(not(feature = "user-operations"))]
@@ -105,6 +147,60 @@ fn main() {
     collective::reduce_local_into(&a, &mut e, SystemOperation::bitwise_xor());
     assert_eq!(e, 0b0011_0011_1100_1100);
 
+    // every process sets the bit corresponding to its rank in a capability mask
+    let capability = 1u64 << (rank % 64);
+    let mut capabilities = 0u64;
+    world.all_reduce_into(
+        &capability,
+        &mut capabilities,
+        SystemOperation::bitwise_or(),
+    );
+    assert_eq!(capabilities.count_ones(), size.min(64) as u32);
+
+    let mut all_ready = false;
+    world.all_reduce_into(&(rank >= 0), &mut all_ready, SystemOperation::logical_and());
+    assert!(all_ready);
+
+    let mut any_root = false;
+    world.all_reduce_into(
+        &(rank == root_rank),
+        &mut any_root,
+        SystemOperation::logical_or(),
+    );
+    assert!(any_root);
+
+    // bitwise operations are not defined for floating point numbers
+    if cfg!(debug_assertions) {
+        let mut x = 0.0f64;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            world.all_reduce_into(&1.5f64, &mut x, SystemOperation::bitwise_or());
+        }));
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<&str>().unwrap();
+        assert!(message.contains("not defined for the datatype"));
+    }
+
+    // find the rank holding the largest and the smallest value
+    let value = f64::from((rank + 1) % size);
+    let mut max_loc = ValueIndex::default();
+    world.all_reduce_into(
+        &ValueIndex::new(value, rank),
+        &mut max_loc,
+        SystemOperation::max_loc(),
+    );
+    assert_eq!(
+        max_loc,
+        ValueIndex::new(f64::from(size - 1), (size - 2).max(0))
+    );
+
+    let mut min_loc = ValueIndex::default();
+    world.all_reduce_into(
+        &ValueIndex::new(value, rank),
+        &mut min_loc,
+        SystemOperation::min_loc(),
+    );
+    assert_eq!(min_loc, ValueIndex::new(0.0, size - 1));
+
     let f = (0..size).collect::<Vec<_>>();
     let mut g: Rank = 0;
 
@@ -117,4 +213,33 @@ fn main() {
     let op = unsafe { UnsafeUserOperation::commutative(unsafe_add) };
     world.all_reduce_into(&(rank + 1), &mut i, &op);
     assert_eq!(i, size * (size + 1) / 2);
+
+    // contributions are summed in rank order, so the result matches a serial sum exactly
+    let x = [1.0 / (rank as f64 + 1.0), 0.1 * rank as f64];
+    let mut sum = [0.0; 2];
+    world.all_reduce_deterministic_into(&x[..], &mut sum[..], |a, b| a + b);
+    let expected = (0..size).fold([0.0, 0.0], |acc, r| {
+        [acc[0] + 1.0 / (r as f64 + 1.0), acc[1] + 0.1 * r as f64]
+    });
+    assert_eq!(expected[0].to_bits(), sum[0].to_bits());
+    assert_eq!(expected[1].to_bits(), sum[1].to_bits());
+
+    let total = world
+        .process_at_rank(0)
+        .reduce(&rank, SystemOperation::sum());
+    if rank == 0 {
+        assert_eq!(Some(size * (size - 1) / 2), total);
+    } else {
+        assert_eq!(None, total);
+    }
+
+    let total = world.all_reduce(&rank, SystemOperation::sum());
+    assert_eq!(size * (size - 1) / 2, total);
+    let max_time = world.all_reduce(&(f64::from(rank) * 0.5), SystemOperation::max());
+    assert_eq!(f64::from(size - 1) * 0.5, max_time);
+
+    let x = vec![rank; 1 << 16];
+    let mut y = vec![0; x.len()];
+    world.all_reduce_into_c(&x[..], &mut y[..], SystemOperation::sum());
+    assert!(y.iter().all(|&y_i| y_i == size * (size - 1) / 2));
 }
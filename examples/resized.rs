@@ -0,0 +1,49 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype};
+use mpi::topology::Rank;
+use mpi::traits::*;
+use std::mem::size_of;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    // every process contributes one column of a 2 x size matrix stored in row-major order
+    let column = [rank, rank + size];
+
+    if rank == root_rank {
+        let mut matrix = vec![-1 as Rank; 2 * size as usize];
+
+        let t = UserDatatype::vector(2, 1, size, &Rank::equivalent_datatype());
+        let (_, extent) = t.get_extent();
+        assert_eq!(
+            ((size + 1) as usize * size_of::<Rank>()) as mpi::Address,
+            extent
+        );
+
+        // shrink the extent to a single element so consecutive columns are placed side by side
+        let t = UserDatatype::resized(&t, 0, size_of::<Rank>() as mpi::Address);
+        assert_eq!((0, size_of::<Rank>() as mpi::Address), t.get_extent());
+        assert_eq!(
+            (0, ((size + 1) as usize * size_of::<Rank>()) as mpi::Address),
+            t.get_true_extent()
+        );
+
+        {
+            let mut v = unsafe { MutView::with_count_and_datatype(&mut matrix[..], size, &t) };
+            root_process.gather_into_root(&column[..], &mut v);
+        }
+
+        println!("Root gathered matrix: {:?}.", matrix);
+        assert_eq!((0..2 * size).collect::<Vec<_>>(), matrix);
+    } else {
+        root_process.gather_into(&column[..]);
+    }
+}
@@ -20,4 +20,13 @@ fn main() {
         root_process.scatter_into(&mut x);
     }
     assert_eq!(x, rank);
+
+    // one parameter per process, e.g. a step size
+    let steps = (0..size).map(|r| 0.5 * f64::from(r)).collect::<Vec<_>>();
+    let step = root_process.scatter(if rank == root_rank {
+        Some(&steps[..])
+    } else {
+        None
+    });
+    assert_eq!(0.5 * f64::from(rank), step);
 }
@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    // a length that is not divisible by most communicator sizes
+    let len = 4 * size + 3;
+    let global: Vec<i32> = (0..len).collect();
+
+    let chunk = if rank == root_rank {
+        root_process.scatter_slice(Some(&global[..]))
+    } else {
+        root_process.scatter_slice(None)
+    };
+    println!("Rank {} got chunk {:?}.", rank, chunk);
+
+    // the first `len % size` ranks get one element more
+    let expected_len = len / size + if rank < len % size { 1 } else { 0 };
+    assert_eq!(expected_len as usize, chunk.len());
+    assert!(chunk.windows(2).all(|w| w[1] == w[0] + 1));
+
+    let doubled: Vec<i32> = chunk.iter().map(|x| 2 * x).collect();
+    let gathered = root_process.gather_slice(&doubled[..]);
+
+    if rank == root_rank {
+        let gathered = gathered.unwrap();
+        assert_eq!(global.iter().map(|x| 2 * x).collect::<Vec<_>>(), gathered);
+    } else {
+        assert!(gathered.is_none());
+    }
+}
@@ -45,4 +45,13 @@ fn main() {
     let mut x = rank;
     p2p::send_receive_replace_into(&mut x, &next_process, &previous_process);
     assert_eq!(x, previous_rank);
+
+    // fixed size messages from a known source do not need a status
+    mpi::request::scope(|scope| {
+        let sreq = next_process.immediate_send(scope, &rank);
+        let mut y: Rank = -1;
+        previous_process.receive_into_without_status(&mut y);
+        sreq.wait_without_status();
+        assert_eq!(y, previous_rank);
+    });
 }
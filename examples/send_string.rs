@@ -0,0 +1,43 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank + size - 1) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    let greeting = format!("Grüße von Rang {}", rank);
+    let invalid = [0xffu8, 0xfe];
+
+    mpi::request::scope(|scope| {
+        let greeting_request = next_process.immediate_send_with_tag(scope, greeting.as_bytes(), 1);
+        let invalid_request = next_process.immediate_send_with_tag(scope, &invalid[..], 2);
+
+        let (msg, status) = previous_process.receive_string_with_tag(1);
+        assert_eq!(previous_rank, status.source_rank());
+        assert_eq!(format!("Grüße von Rang {}", previous_rank), msg.unwrap());
+
+        let (msg, _) = previous_process.receive_string_with_tag(2);
+        assert_eq!(&invalid[..], msg.unwrap_err().as_bytes());
+
+        greeting_request.wait();
+        invalid_request.wait();
+    });
+
+    let root_process = world.process_at_rank(0);
+    if rank == 0 {
+        for _ in 1..size {
+            let (msg, status) = world.any_process().receive_string();
+            assert_eq!(format!("done {}", status.source_rank()), msg.unwrap());
+        }
+    } else {
+        root_process.send_str(&format!("done {}", rank));
+    }
+}
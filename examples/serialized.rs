@@ -0,0 +1,43 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::collections::BTreeMap;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+
+    // neither of these types has a fixed layout that could be described by an MPI datatype
+    let inventory = |rank| {
+        (0..=rank)
+            .map(|i| (format!("item {}", i), vec![i; i as usize]))
+            .collect::<BTreeMap<String, Vec<i32>>>()
+    };
+
+    if rank == root_rank {
+        for _ in 1..size {
+            let (msg, status) = world
+                .any_process()
+                .receive_deserialized::<BTreeMap<String, Vec<i32>>>();
+            assert_eq!(inventory(status.source_rank()), msg.unwrap());
+        }
+
+        // bytes that are not a valid encoding result in an error rather than a panic
+        for _ in 1..size {
+            let (msg, _) = world
+                .any_process()
+                .receive_deserialized_with_tag::<(String, Option<f64>)>(1);
+            assert!(msg.is_err());
+        }
+    } else {
+        let root_process = world.process_at_rank(root_rank);
+        root_process.send_serialized(&inventory(rank)).unwrap();
+        root_process.send_with_tag(&[0xffu8; 3][..], 1);
+    }
+}
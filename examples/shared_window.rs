@@ -0,0 +1,31 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::window::SharedWindow;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // the processes of a node share memory
+    let node = world.split_shared(world.rank());
+    let rank = node.rank();
+
+    let mut window = SharedWindow::allocate(&node, 2, -1);
+    window.local_mut()[0] = rank;
+    window.local_mut()[1] = rank * rank;
+
+    // make the stores of all processes visible before reading them
+    window.fence();
+
+    assert_eq!(&[rank, rank * rank], window.local());
+    for peer in 0..node.size() {
+        let (segment, disp_unit) = unsafe { window.query(peer) };
+        assert_eq!(std::mem::size_of::<i32>() as mpi::Address, disp_unit);
+        assert_eq!(&[peer, peer * peer], segment);
+    }
+
+    // all processes are done reading before the window is freed
+    window.fence();
+}
@@ -0,0 +1,46 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::datatype::{MutView, UserDatatype, View};
+use mpi::topology::Color;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    // every process ends up alone in its own communicator
+    let alone = world.split_by_color(Color::with_value(rank)).unwrap();
+    assert_eq!(1, alone.size());
+
+    let x = [rank, rank + 1, rank + 2];
+
+    let mut y = [0; 3];
+    alone.all_gather_into(&x[..], &mut y[..]);
+    assert_eq!(x, y);
+
+    let mut y = [0; 3];
+    alone.all_reduce_into(&x[..], &mut y[..], SystemOperation::sum());
+    assert_eq!(x, y);
+
+    let mut y = x;
+    alone.process_at_rank(0).broadcast_into(&mut y[..]);
+    assert_eq!(x, y);
+
+    // derived datatypes are not copied locally but still give the same results
+    let d = UserDatatype::contiguous(3, &i32::equivalent_datatype());
+    let mut y = [0; 3];
+    {
+        let sv = unsafe { View::with_count_and_datatype(&x[..], 1, &d) };
+        let mut rv = unsafe { MutView::with_count_and_datatype(&mut y[..], 1, &d) };
+        alone.all_gather_into(&sv, &mut rv);
+    }
+    assert_eq!(x, y);
+
+    // on larger communicators, MPI is used as usual
+    let mut sum = 0;
+    world.all_reduce_into(&rank, &mut sum, SystemOperation::sum());
+    assert_eq!(world.size() * (world.size() - 1) / 2, sum);
+}
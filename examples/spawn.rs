@@ -0,0 +1,45 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::env;
+use std::process::Command;
+
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+const NUM_WORKERS: Rank = 2;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    assert_eq!(mpi::is_spawned(), universe.parent().is_some());
+
+    if let Some(parent) = universe.parent() {
+        // this is one of the spawned workers
+        assert_eq!(NUM_WORKERS, world.size());
+
+        let merged = parent.merge(true);
+        let mut value: Rank = 0;
+        merged.process_at_rank(0).broadcast_into(&mut value);
+        println!(
+            "Worker {} received value {} from its parent.",
+            world.rank(),
+            value
+        );
+        assert_eq!(merged.size() - NUM_WORKERS, value);
+    } else {
+        let program = env::current_exe().expect("Could not determine path of this program.");
+        let workers = world
+            .process_at_rank(0)
+            .spawn(&Command::new(program), NUM_WORKERS);
+        assert_eq!(NUM_WORKERS, workers.remote_size());
+
+        // the parents are ordered before the workers in the merged communicator
+        let merged = workers.merge(false);
+        assert_eq!(world.size() + NUM_WORKERS, merged.size());
+        assert_eq!(world.rank(), merged.rank());
+
+        let mut value = world.size();
+        merged.process_at_rank(0).broadcast_into(&mut value);
+    }
+}
@@ -70,6 +70,21 @@ fn main() {
         oddness_comm.group().compare(my_group)
     );
 
+    // ranks in the sub-communicator map back to the world ranks of the same oddness
+    let sub_ranks = (0..oddness_comm.size()).collect::<Vec<_>>();
+    let world_ranks = oddness_comm.translate_ranks_to(&sub_ranks[..], &world);
+    assert!(world_ranks
+        .iter()
+        .all(|r| r.map_or(false, |r| r % 2 == world.rank() % 2)));
+    assert_eq!(
+        Some(world.rank()),
+        oddness_comm.translate_rank_to(oddness_comm.rank(), &world)
+    );
+    let neighbour = world.rank() ^ 1;
+    if neighbour < world.size() {
+        assert_eq!(None, world.translate_rank_to(neighbour, &oddness_comm));
+    }
+
     let odd_comm = world.split_by_color(if world.rank() % 2 != 0 {
         Color::with_value(0)
     } else {
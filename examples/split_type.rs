@@ -0,0 +1,33 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::topology::SplitType;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let node = world
+        .split_by_type(SplitType::Shared, world.rank())
+        .expect("Every process should join a shared memory communicator.");
+    assert!(node.size() <= world.size());
+
+    // every process is the leader of at most one node, so the node sizes add up to the world
+    let is_leader = node.rank() == 0;
+    let leader_count = if is_leader { node.size() } else { 0 };
+    let mut total = 0;
+    world.all_reduce_into(&leader_count, &mut total, SystemOperation::sum());
+    assert_eq!(world.size(), total);
+
+    println!(
+        "World rank {} is rank {} of {} on its node.",
+        world.rank(),
+        node.rank(),
+        node.size()
+    );
+
+    let none = world.split_by_type(SplitType::Undefined, 0);
+    assert!(none.is_none());
+}
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use mpi::{
+    datatype::{UserDatatype, View},
     topology::{Communicator, SystemCommunicator},
     traits::*,
 };
@@ -177,4 +178,109 @@ fn main() {
             child: Child(3.4, 7),
         },
     );
+
+    #[derive(Equivalence, Clone, Copy, PartialEq, Debug)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Phase {
+        A,
+        B = 7,
+        C,
+    }
+
+    assert_equivalence(&world, &Phase::B, &7u8);
+
+    #[derive(Equivalence, Clone, Copy, PartialEq, Debug)]
+    #[repr(u8)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rectangle { width: f32, height: f32 },
+        Polygon([(i16, i16); 3]),
+    }
+
+    let shapes = [
+        Shape::Circle(1.5),
+        Shape::Rectangle {
+            width: 2.0,
+            height: 0.5,
+        },
+        Shape::Polygon([(0, 0), (1, 0), (0, 1)]),
+        Shape::Empty,
+    ];
+    let packed = world.pack(&shapes[..]);
+    let mut unpacked = [Shape::Empty; 4];
+    unsafe {
+        world.unpack_into(&packed, &mut unpacked[..], 0);
+    }
+    assert_eq!(shapes, unpacked);
+
+    #[derive(Equivalence, Default, PartialEq, Debug)]
+    struct Packet<T> {
+        b: bool,
+        payload: T,
+    }
+
+    assert_equivalence(
+        &world,
+        &MyDataRust {
+            b: true,
+            f: 3.4,
+            i: 7,
+        },
+        &Packet {
+            b: true,
+            payload: Child(3.4, 7),
+        },
+    );
+
+    // the same generic struct instantiated at other payload types
+    #[derive(Equivalence, Default, PartialEq, Debug)]
+    struct BoolDouble(bool, f64);
+
+    assert_equivalence(
+        &world,
+        &Packet {
+            b: false,
+            payload: 2.5f64,
+        },
+        &BoolDouble(false, 2.5),
+    );
+
+    let mut packet = if world.rank() == 0 {
+        Packet {
+            b: true,
+            payload: [1i32, 2, 3],
+        }
+    } else {
+        Packet::default()
+    };
+    world.process_at_rank(0).broadcast_into(&mut packet);
+    assert_eq!(
+        Packet {
+            b: true,
+            payload: [1, 2, 3]
+        },
+        packet
+    );
+
+    #[derive(Equivalence, PartialEq, Debug)]
+    struct Vector<const N: usize>([f64; N]);
+
+    assert_equivalence(&world, &Vector([1.0, 2.0, 3.0]), &[1.0f64, 2.0, 3.0]);
+
+    // the datatype of a non-generic struct is committed once and reused afterwards
+    assert_eq!(
+        MyDataRust::equivalent_datatype().as_raw(),
+        MyDataRust::equivalent_datatype().as_raw()
+    );
+
+    let pair = UserDatatype::contiguous(2, &Child::equivalent_datatype());
+    let children = [Child(3.4, 7), Child(5.6, 8)];
+    let packed = world.pack(&unsafe { View::with_count_and_datatype(&children[..], 1, &pair) });
+    let mut unpacked = [Child::default(), Child::default()];
+    unsafe {
+        world.unpack_into(&packed, &mut unpacked[..], 0);
+    }
+    assert_eq!(children, unpacked);
 }
@@ -0,0 +1,76 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{address_of, MutView, StructDatatypeBuilder};
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::size_of;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct Record {
+    id: i32,
+    values: [f64; 2],
+    flag: u8,
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_process = world.process_at_rank(0);
+
+    // pretend that the layout of `Record` has been read from a schema at run time
+    let probe = Record::default();
+    let base = address_of(&probe);
+    let int = i32::equivalent_datatype();
+    let double = f64::equivalent_datatype();
+    let byte = u8::equivalent_datatype();
+
+    let id_offset = address_of(&probe.id) - base;
+    let values_offset = address_of(&probe.values) - base;
+    let flag_offset = address_of(&probe.flag) - base;
+
+    let mut builder = StructDatatypeBuilder::new();
+    builder
+        .field(1, id_offset, &int)
+        .field(2, values_offset, &double)
+        .field(1, flag_offset, &byte);
+    assert_eq!(3, builder.len());
+
+    // MPI rounds the extent up to the alignment of the widest field, which reproduces the trailing
+    // padding of this `#[repr(C)]` struct
+    let datatype = builder.build();
+    assert_eq!((0, size_of::<Record>() as Address), datatype.get_extent());
+
+    // a datatype that leaves out `flag` ends after `values`, so its extent is shorter than the
+    // stride of `Record`s in memory and has to be set explicitly
+    let mut partial = StructDatatypeBuilder::new();
+    partial
+        .field(1, id_offset, &int)
+        .field(2, values_offset, &double);
+    let values_end = values_offset + 2 * size_of::<f64>() as Address;
+    assert_eq!((0, values_end), partial.build().get_extent());
+    let partial = partial.build_with_extent(size_of::<Record>() as Address);
+    assert_eq!((0, size_of::<Record>() as Address), partial.get_extent());
+
+    let expected = (0..4)
+        .map(|i| Record {
+            id: i,
+            values: [f64::from(i), -f64::from(i)],
+            flag: i as u8 % 2,
+        })
+        .collect::<Vec<_>>();
+
+    let mut records = if world.rank() == 0 {
+        expected.clone()
+    } else {
+        vec![Record::default(); expected.len()]
+    };
+    {
+        let count = records.len() as _;
+        let mut view =
+            unsafe { MutView::with_count_and_datatype(&mut records[..], count, &datatype) };
+        root_process.broadcast_into(&mut view);
+    }
+    assert_eq!(expected, records);
+}
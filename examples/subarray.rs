@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, Order, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = if rank > 0 { rank - 1 } else { size - 1 };
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // a 4 x 4 matrix stored in row-major order
+    let b1 = (0..).map(|x| rank * x).take(16).collect::<Vec<_>>();
+    let mut b2 = std::iter::repeat(-1).take(16).collect::<Vec<_>>();
+    println!("Rank {} sending message: {:?}.", rank, b1);
+    world.barrier();
+
+    // the 2 x 2 block in the center of the matrix
+    let t = UserDatatype::subarray(
+        &[4, 4],
+        &[2, 2],
+        &[1, 1],
+        Order::C,
+        &Rank::equivalent_datatype(),
+    );
+    let status;
+    {
+        let v1 = unsafe { View::with_count_and_datatype(&b1[..], 1, &t) };
+        let mut v2 = unsafe { MutView::with_count_and_datatype(&mut b2[..], 1, &t) };
+        status = p2p::send_receive_into(&v1, &next_process, &mut v2, &previous_process);
+    }
+
+    println!(
+        "Rank {} received message: {:?}, status: {:?}.",
+        rank, b2, status
+    );
+    world.barrier();
+
+    let b3 = (0..)
+        .map(|x| match x {
+            5 | 6 | 9 | 10 => previous_rank * x,
+            _ => -1,
+        })
+        .take(16)
+        .collect::<Vec<_>>();
+    assert_eq!(b3, b2);
+}
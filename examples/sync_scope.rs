@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::thread;
+
+use mpi::topology::{Rank, SystemCommunicator};
+use mpi::traits::*;
+use mpi::Threading;
+
+const THREADS: usize = 4;
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    if threading != Threading::Multiple {
+        println!("Skipping, MPI does not support Threading::Multiple.");
+        return;
+    }
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank + size - 1) % size;
+
+    // buffers are declared outside of the scope, one per thread
+    let messages = (0..THREADS as Rank)
+        .map(|t| rank * THREADS as Rank + t)
+        .collect::<Vec<_>>();
+    let mut received = vec![-1; THREADS];
+
+    mpi::request::sync_scope(|scope| {
+        thread::scope(|s| {
+            for (t, (message, buffer)) in messages.iter().zip(received.iter_mut()).enumerate() {
+                // every thread posts its own requests on the shared scope
+                s.spawn(move || {
+                    let world = SystemCommunicator::world();
+                    let tag = t as i32;
+                    let rreq = world
+                        .process_at_rank(previous_rank)
+                        .immediate_receive_into_with_tag(scope, buffer, tag);
+                    let sreq = world
+                        .process_at_rank(next_rank)
+                        .immediate_send_with_tag(scope, message, tag);
+                    rreq.wait();
+                    sreq.wait();
+                });
+            }
+        });
+    });
+
+    let expected = (0..THREADS as Rank)
+        .map(|t| previous_rank * THREADS as Rank + t)
+        .collect::<Vec<_>>();
+    assert_eq!(expected, received);
+}
@@ -0,0 +1,42 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // the standard guarantees at least this many tags
+    let upper_bound = universe.tag_upper_bound();
+    assert!(upper_bound >= 32767);
+    assert_eq!(upper_bound, mpi::environment::tag_upper_bound());
+
+    // the largest tag is a valid tag
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank + size - 1) % size;
+    mpi::request::scope(|scope| {
+        let sreq =
+            world
+                .process_at_rank(next_rank)
+                .immediate_send_with_tag(scope, &rank, upper_bound);
+        let (msg, status) = world
+            .process_at_rank(previous_rank)
+            .receive_with_tag::<i32>(upper_bound);
+        sreq.wait();
+        assert_eq!(previous_rank, msg);
+        assert_eq!(upper_bound, status.tag());
+    });
+
+    // in debug builds, tags out of range are caught before they reach MPI
+    if cfg!(debug_assertions) {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            world.process_at_rank(next_rank).send_with_tag(&rank, -2);
+        }));
+        assert!(result.is_err());
+    }
+}
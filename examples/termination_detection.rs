@@ -0,0 +1,69 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+const TAG: mpi::Tag = 7;
+
+/// Every process sends to a few neighbours that do not know in advance how many messages they will
+/// receive.
+fn destinations(rank: Rank, size: Rank) -> impl Iterator<Item = Rank> {
+    (1..=rank % 3).map(move |k| (rank + k) % size)
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // Non-blocking consensus: synchronous sends complete only once they have been received, so
+    // once all of its sends are complete a process enters a non-blocking barrier. It keeps
+    // receiving messages until the barrier completes, which means every process has finished
+    // sending and all messages have been received.
+    let mut received = Vec::new();
+    mpi::request::scope(|scope| {
+        let mut sends = destinations(rank, size)
+            .map(|destination| {
+                world
+                    .process_at_rank(destination)
+                    .immediate_synchronous_send_with_tag(scope, &rank, TAG)
+            })
+            .collect::<Vec<_>>();
+        let mut barrier = None;
+
+        loop {
+            if let Some((msg, _)) = world.any_process().immediate_matched_probe_with_tag(TAG) {
+                received.push(msg.matched_receive::<Rank>().0);
+            }
+
+            match barrier.take() {
+                Some(request) => match request.test() {
+                    Ok(_) => break,
+                    Err(request) => barrier = Some(request),
+                },
+                None => {
+                    sends = sends
+                        .into_iter()
+                        .filter_map(|request| request.test().err())
+                        .collect();
+                    if sends.is_empty() {
+                        barrier = Some(world.immediate_barrier());
+                    }
+                }
+            }
+        }
+    });
+
+    let mut expected = (0..size)
+        .flat_map(|source| {
+            destinations(source, size)
+                .filter(move |&destination| destination == rank)
+                .map(move |_| source)
+        })
+        .collect::<Vec<_>>();
+    expected.sort_unstable();
+    received.sort_unstable();
+    assert_eq!(expected, received);
+}
@@ -16,4 +16,16 @@ fn main() {
         "the clock has a resoltion of {} seconds",
         mpi::time_resolution()
     );
+
+    let elapsed = mpi::environment::time_since(t_start);
+    println!("{:?} have passed since the start of the barrier", elapsed);
+
+    println!(
+        "the clocks are {}synchronized across processes",
+        if mpi::environment::time_is_global() {
+            ""
+        } else {
+            "not "
+        }
+    );
 }
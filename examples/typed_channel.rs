@@ -0,0 +1,43 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::point_to_point::TypedChannel;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let server_rank = 0;
+    let requests = TypedChannel::<i64>::new(&world, 1);
+    let replies = TypedChannel::<i64>::new(&world, 2);
+    assert_eq!(1, requests.tag());
+    assert_eq!(size, replies.communicator().size());
+
+    // traffic on the world communicator does not interfere with the channels
+    if rank != server_rank {
+        world.process_at_rank(server_rank).send_with_tag(&-1i64, 1);
+    }
+
+    if rank == server_rank {
+        for _ in 1..size {
+            let (x, client) = requests.recv_any();
+            assert_eq!(i64::from(client), x);
+            replies.send(client, &(x * x));
+        }
+        for _ in 1..size {
+            let (x, _) = world.any_process().receive_with_tag::<i64>(1);
+            assert_eq!(-1, x);
+        }
+    } else {
+        requests.send(server_rank, &i64::from(rank));
+        let y = replies.recv(server_rank);
+        assert_eq!(i64::from(rank) * i64::from(rank), y);
+    }
+
+    world.barrier();
+    assert_eq!(None, requests.try_recv(server_rank));
+    assert_eq!(None, replies.try_recv(server_rank));
+}
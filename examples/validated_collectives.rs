@@ -0,0 +1,47 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use mpi::collective::SystemOperation;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // consistent arguments behave like the unchecked collectives
+    let x = [rank, 1];
+    let mut sum = [0, 0];
+    world.all_reduce_validated_into(&x[..], &mut sum[..], SystemOperation::sum());
+    assert_eq!([size * (size - 1) / 2, size], sum);
+
+    let mut value = if rank == 0 { 42 } else { 0 };
+    world
+        .process_at_rank(0)
+        .broadcast_validated_into(&mut value);
+    assert_eq!(42, value);
+
+    if cfg!(debug_assertions) && size > 1 {
+        // the root disagrees on the count, all processes panic before reducing anything
+        let count = if rank == 0 { 2 } else { 1 };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            world.all_reduce_validated_into(&x[..count], &mut sum[..count], SystemOperation::sum());
+        }));
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(message.contains("disagree on the count"));
+
+        // processes disagree on the root
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            world
+                .process_at_rank(rank % 2)
+                .broadcast_validated_into(&mut value);
+        }));
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(message.contains("disagree on the root rank"));
+    }
+}
@@ -10,7 +10,7 @@ use syn::{Fields, Type};
 pub fn create_user_datatype(input: TokenStream1) -> TokenStream1 {
     let ast: syn::DeriveInput = syn::parse(input).expect("Couldn't parse struct");
     let result = match ast.data {
-        syn::Data::Enum(_) => panic!("#[derive(Equivalence)] is not compatible with enums"),
+        syn::Data::Enum(ref e) => equivalence_for_enum(&ast, e),
         syn::Data::Union(_) => panic!("#[derive(Equivalence)] is not compatible with unions"),
         syn::Data::Struct(ref s) => equivalence_for_struct(&ast, &s.fields),
     };
@@ -47,14 +47,14 @@ fn equivalence_for_array_field(type_array: &syn::TypeArray) -> TokenStream2 {
     // {integer}. We know that `#len` should be of type `usize` because it is an
     // array size.
     quote! { &::mpi::datatype::UncommittedUserDatatype::contiguous(
-        {let len: usize = #len; len}.try_into().expect("rsmpi derive: Array size is to large for MPI_Datatype i32"), &#ty)
+        {let len: usize = #len; len}.try_into().expect("rsmpi derive: Array size is to large for MPI_Datatype i32"), #ty)
     }
 }
 
 fn equivalence_for_type(ty: &syn::Type) -> TokenStream2 {
     match ty {
         Type::Path(ref type_path) => quote!(
-                <#type_path as ::mpi::datatype::Equivalence>::equivalent_datatype()),
+                &<#type_path as ::mpi::datatype::Equivalence>::equivalent_datatype()),
         Type::Tuple(ref type_tuple) => equivalence_for_tuple_field(&type_tuple),
         Type::Array(ref type_array) => equivalence_for_array_field(&type_array),
         _ => panic!("Unsupported type!"),
@@ -64,6 +64,22 @@ fn equivalence_for_type(ty: &syn::Type) -> TokenStream2 {
 fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStream2 {
     let ident = &ast.ident;
 
+    // Every type parameter has to be `Equivalence` for its fields to be describable.
+    let mut generics = ast.generics.clone();
+    let type_params: Vec<syn::Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    {
+        let where_clause = generics.make_where_clause();
+        for param in type_params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#param: ::mpi::datatype::Equivalence));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     let field_blocklengths = fields.iter().map(|_| 1);
 
     let field_names = fields
@@ -83,6 +99,43 @@ fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStrea
 
     let ident_str = ident.to_string();
 
+    let datatype = quote! {
+        ::mpi::datatype::internal::check_derive_equivalence_universe_state(#ident_str);
+
+        let datatype = ::mpi::datatype::UserDatatype::structured::<
+            ::mpi::datatype::UncommittedDatatypeRef,
+        >(
+            &[#(#field_blocklengths as ::mpi::Count),*],
+            &[#(::mpi::internal::memoffset::offset_of!(#ident #ty_generics, #field_names) as ::mpi::Address),*],
+            &[#(::mpi::datatype::UncommittedDatatypeRef::from(#field_datatypes)),*],
+        );
+
+        // Catch padding or alignment that is not reflected in the fields, otherwise slices of
+        // this type would be misinterpreted.
+        ::mpi::datatype::internal::check_derive_equivalence_extent(
+            #ident_str,
+            &datatype,
+            ::std::mem::size_of::<#ident #ty_generics>(),
+        );
+
+        datatype
+    };
+
+    if !ast.generics.params.is_empty() {
+        // A static cannot depend on generic parameters, so the datatype of a generic struct is
+        // constructed anew for every call.
+        return quote! {
+            unsafe impl #impl_generics ::mpi::datatype::Equivalence for #ident #ty_generics #where_clause {
+                type Out = ::mpi::datatype::UserDatatype;
+                fn equivalent_datatype() -> Self::Out {
+                    use ::std::convert::TryInto;
+
+                    #datatype
+                }
+            }
+        };
+    }
+
     // TODO and NOTE: Technically this code can race with MPI init and finalize, as can any other
     // code in rsmpi that interacts with the MPI library without taking a handle to `Universe`.
     // This requires larger attention, and so currently this is not addressed.
@@ -93,16 +146,146 @@ fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStrea
                 use ::mpi::internal::once_cell::sync::Lazy;
                 use ::std::convert::TryInto;
 
+                static DATATYPE: Lazy<::mpi::datatype::UserDatatype> = Lazy::new(|| {
+                    #datatype
+                });
+
+                DATATYPE.as_ref()
+            }
+        }
+    }
+}
+
+fn equivalence_for_enum(ast: &syn::DeriveInput, data_enum: &syn::DataEnum) -> TokenStream2 {
+    let ident = &ast.ident;
+
+    let repr = ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) => path.get_ident().cloned(),
+            _ => None,
+        })
+        .find(|repr| {
+            [
+                "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+            ]
+            .iter()
+            .any(|int| repr == int)
+        })
+        .unwrap_or_else(|| {
+            panic!("#[derive(Equivalence)] requires enums to have a primitive integer #[repr]")
+        });
+
+    if data_enum
+        .variants
+        .iter()
+        .any(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return equivalence_for_tagged_enum(ast, data_enum, &repr);
+    }
+
+    // NOTE: Receiving a value that is not a valid discriminant of the enum is undefined behavior.
+    // This is the same contract as for any other `Equivalence` type whose values are restricted,
+    // e.g. `bool`.
+    quote! {
+        unsafe impl ::mpi::datatype::Equivalence for #ident {
+            type Out = <#repr as ::mpi::datatype::Equivalence>::Out;
+            fn equivalent_datatype() -> Self::Out {
+                <#repr as ::mpi::datatype::Equivalence>::equivalent_datatype()
+            }
+        }
+    }
+}
+
+/// Asserts that every (nested) field type implements `Equivalence`, i.e. is plain data.
+fn assert_equivalence_for_type(ty: &syn::Type) -> TokenStream2 {
+    match ty {
+        Type::Path(ref type_path) => quote!(assert_equivalence::<#type_path>();),
+        Type::Tuple(ref type_tuple) => {
+            let elems = type_tuple.elems.iter().map(assert_equivalence_for_type);
+            quote!(#(#elems)*)
+        }
+        Type::Array(ref type_array) => assert_equivalence_for_type(&type_array.elem),
+        _ => panic!("Unsupported type!"),
+    }
+}
+
+fn equivalence_for_tagged_enum(
+    ast: &syn::DeriveInput,
+    data_enum: &syn::DataEnum,
+    repr: &syn::Ident,
+) -> TokenStream2 {
+    let ident = &ast.ident;
+
+    if !ast.generics.params.is_empty() {
+        panic!("#[derive(Equivalence)] is not compatible with generic enums with fields");
+    }
+
+    let field_assertions = data_enum
+        .variants
+        .iter()
+        .flat_map(|variant| variant.fields.iter())
+        .map(|field| assert_equivalence_for_type(&field.ty));
+
+    let ident_str = ident.to_string();
+
+    // With a primitive `#[repr]`, every variant is laid out like a `#[repr(C)]` struct of the tag
+    // followed by the fields of the variant. The tag is described by its integer type and the
+    // payload of the largest variant, including any padding, by bytes, so that the receiving side
+    // reconstructs the same variant. This is only meaningful between processes that agree on the
+    // memory layout of the enum.
+    //
+    // NOTE: As for enums without fields, receiving a value that is not a valid discriminant of the
+    // enum is undefined behavior.
+    quote! {
+        unsafe impl ::mpi::datatype::Equivalence for #ident {
+            type Out = ::mpi::datatype::DatatypeRef<'static>;
+            fn equivalent_datatype() -> Self::Out {
+                use ::mpi::internal::once_cell::sync::Lazy;
+                use ::std::convert::TryInto;
+
                 static DATATYPE: Lazy<::mpi::datatype::UserDatatype> = Lazy::new(|| {
                     ::mpi::datatype::internal::check_derive_equivalence_universe_state(#ident_str);
 
-                    ::mpi::datatype::UserDatatype::structured::<
+                    fn assert_equivalence<T: ::mpi::datatype::Equivalence>() {}
+                    #(#field_assertions)*
+
+                    let tag = ::std::mem::size_of::<#repr>();
+                    let size = ::std::mem::size_of::<#ident>();
+                    let datatype = ::mpi::datatype::UserDatatype::structured::<
                         ::mpi::datatype::UncommittedDatatypeRef,
                     >(
-                        &[#(#field_blocklengths as ::mpi::Count),*],
-                        &[#(::mpi::internal::memoffset::offset_of!(#ident, #field_names) as ::mpi::Address),*],
-                        &[#(::mpi::datatype::UncommittedDatatypeRef::from(#field_datatypes)),*],
-                    )
+                        &[
+                            1,
+                            (size - tag)
+                                .try_into()
+                                .expect("rsmpi derive: Enum size is too large for MPI Count"),
+                        ],
+                        &[0, tag as ::mpi::Address],
+                        &[
+                            ::mpi::datatype::UncommittedDatatypeRef::from(
+                                &<#repr as ::mpi::datatype::Equivalence>::equivalent_datatype(),
+                            ),
+                            ::mpi::datatype::UncommittedDatatypeRef::from(
+                                &<u8 as ::mpi::datatype::Equivalence>::equivalent_datatype(),
+                            ),
+                        ],
+                    );
+
+                    ::mpi::datatype::internal::check_derive_equivalence_extent(
+                        #ident_str,
+                        &datatype,
+                        size,
+                    );
+
+                    datatype
                 });
 
                 DATATYPE.as_ref()
@@ -4,6 +4,8 @@ const MPI_Datatype RSMPI_C_BOOL = MPI_C_BOOL;
 
 const MPI_Datatype RSMPI_FLOAT = MPI_FLOAT;
 const MPI_Datatype RSMPI_DOUBLE = MPI_DOUBLE;
+const MPI_Datatype RSMPI_C_FLOAT_COMPLEX = MPI_C_FLOAT_COMPLEX;
+const MPI_Datatype RSMPI_C_DOUBLE_COMPLEX = MPI_C_DOUBLE_COMPLEX;
 
 const MPI_Datatype RSMPI_INT8_T = MPI_INT8_T;
 const MPI_Datatype RSMPI_INT16_T = MPI_INT16_T;
@@ -15,8 +17,40 @@ const MPI_Datatype RSMPI_UINT16_T = MPI_UINT16_T;
 const MPI_Datatype RSMPI_UINT32_T = MPI_UINT32_T;
 const MPI_Datatype RSMPI_UINT64_T = MPI_UINT64_T;
 
+const MPI_Datatype RSMPI_BYTE = MPI_BYTE;
+
+const MPI_Datatype RSMPI_INTEGER = MPI_INTEGER;
+const MPI_Datatype RSMPI_REAL = MPI_REAL;
+const MPI_Datatype RSMPI_DOUBLE_PRECISION = MPI_DOUBLE_PRECISION;
+const MPI_Datatype RSMPI_COMPLEX = MPI_COMPLEX;
+const MPI_Datatype RSMPI_DOUBLE_COMPLEX = MPI_DOUBLE_COMPLEX;
+const MPI_Datatype RSMPI_LOGICAL = MPI_LOGICAL;
+const MPI_Datatype RSMPI_CHARACTER = MPI_CHARACTER;
+
+const MPI_Datatype RSMPI_FLOAT_INT = MPI_FLOAT_INT;
+const MPI_Datatype RSMPI_DOUBLE_INT = MPI_DOUBLE_INT;
+const MPI_Datatype RSMPI_SHORT_INT = MPI_SHORT_INT;
+const MPI_Datatype RSMPI_2INT = MPI_2INT;
+
 const MPI_Datatype RSMPI_DATATYPE_NULL = MPI_DATATYPE_NULL;
 
+const int RSMPI_ORDER_C = MPI_ORDER_C;
+const int RSMPI_ORDER_FORTRAN = MPI_ORDER_FORTRAN;
+
+const int RSMPI_COMBINER_NAMED = MPI_COMBINER_NAMED;
+const int RSMPI_COMBINER_DUP = MPI_COMBINER_DUP;
+const int RSMPI_COMBINER_CONTIGUOUS = MPI_COMBINER_CONTIGUOUS;
+const int RSMPI_COMBINER_VECTOR = MPI_COMBINER_VECTOR;
+const int RSMPI_COMBINER_HVECTOR = MPI_COMBINER_HVECTOR;
+const int RSMPI_COMBINER_INDEXED = MPI_COMBINER_INDEXED;
+const int RSMPI_COMBINER_HINDEXED = MPI_COMBINER_HINDEXED;
+const int RSMPI_COMBINER_INDEXED_BLOCK = MPI_COMBINER_INDEXED_BLOCK;
+const int RSMPI_COMBINER_HINDEXED_BLOCK = MPI_COMBINER_HINDEXED_BLOCK;
+const int RSMPI_COMBINER_STRUCT = MPI_COMBINER_STRUCT;
+const int RSMPI_COMBINER_SUBARRAY = MPI_COMBINER_SUBARRAY;
+const int RSMPI_COMBINER_DARRAY = MPI_COMBINER_DARRAY;
+const int RSMPI_COMBINER_RESIZED = MPI_COMBINER_RESIZED;
+
 const MPI_Comm RSMPI_COMM_WORLD = MPI_COMM_WORLD;
 const MPI_Comm RSMPI_COMM_NULL = MPI_COMM_NULL;
 const MPI_Comm RSMPI_COMM_SELF = MPI_COMM_SELF;
@@ -27,9 +61,13 @@ const MPI_Group RSMPI_GROUP_EMPTY = MPI_GROUP_EMPTY;
 const MPI_Group RSMPI_GROUP_NULL = MPI_GROUP_NULL;
 const int RSMPI_UNDEFINED = MPI_UNDEFINED;
 
+const int RSMPI_SUCCESS = MPI_SUCCESS;
+
 const int RSMPI_PROC_NULL = MPI_PROC_NULL;
 const int RSMPI_ANY_SOURCE = MPI_ANY_SOURCE;
 const int RSMPI_ANY_TAG = MPI_ANY_TAG;
+const int RSMPI_ROOT = MPI_ROOT;
+const int RSMPI_BSEND_OVERHEAD = MPI_BSEND_OVERHEAD;
 
 const MPI_Message RSMPI_MESSAGE_NULL = MPI_MESSAGE_NULL;
 const MPI_Message RSMPI_MESSAGE_NO_PROC = MPI_MESSAGE_NO_PROC;
@@ -39,6 +77,8 @@ const MPI_Request RSMPI_REQUEST_NULL = MPI_REQUEST_NULL;
 MPI_Status* const RSMPI_STATUS_IGNORE = MPI_STATUS_IGNORE;
 MPI_Status* const RSMPI_STATUSES_IGNORE = MPI_STATUSES_IGNORE;
 
+int* const RSMPI_ERRCODES_IGNORE = MPI_ERRCODES_IGNORE;
+
 const int RSMPI_IDENT = MPI_IDENT;
 const int RSMPI_CONGRUENT = MPI_CONGRUENT;
 const int RSMPI_SIMILAR = MPI_SIMILAR;
@@ -55,6 +95,10 @@ const int RSMPI_DIST_GRAPH = MPI_DIST_GRAPH;
 
 const int RSMPI_MAX_LIBRARY_VERSION_STRING = MPI_MAX_LIBRARY_VERSION_STRING;
 const int RSMPI_MAX_PROCESSOR_NAME = MPI_MAX_PROCESSOR_NAME;
+const int RSMPI_MAX_ERROR_STRING = MPI_MAX_ERROR_STRING;
+
+const int RSMPI_TAG_UB = MPI_TAG_UB;
+const int RSMPI_WTIME_IS_GLOBAL = MPI_WTIME_IS_GLOBAL;
 
 const MPI_Op RSMPI_MAX = MPI_MAX;
 const MPI_Op RSMPI_MIN = MPI_MIN;
@@ -66,12 +110,18 @@ const MPI_Op RSMPI_LOR = MPI_LOR;
 const MPI_Op RSMPI_BOR = MPI_BOR;
 const MPI_Op RSMPI_LXOR = MPI_LXOR;
 const MPI_Op RSMPI_BXOR = MPI_BXOR;
+const MPI_Op RSMPI_MINLOC = MPI_MINLOC;
+const MPI_Op RSMPI_MAXLOC = MPI_MAXLOC;
 
 const MPI_Errhandler RSMPI_ERRORS_ARE_FATAL = MPI_ERRORS_ARE_FATAL;
 const MPI_Errhandler RSMPI_ERRORS_RETURN = MPI_ERRORS_RETURN;
 
 const MPI_File RSMPI_FILE_NULL = MPI_FILE_NULL;
 
+const int RSMPI_MODE_RDONLY = MPI_MODE_RDONLY;
+const int RSMPI_MODE_WRONLY = MPI_MODE_WRONLY;
+const int RSMPI_MODE_CREATE = MPI_MODE_CREATE;
+
 const MPI_Info RSMPI_INFO_NULL = MPI_INFO_NULL;
 
 const MPI_Win RSMPI_WIN_NULL = MPI_WIN_NULL;
@@ -10,6 +10,8 @@ extern const MPI_Datatype RSMPI_C_BOOL;
 
 extern const MPI_Datatype RSMPI_FLOAT;
 extern const MPI_Datatype RSMPI_DOUBLE;
+extern const MPI_Datatype RSMPI_C_FLOAT_COMPLEX;
+extern const MPI_Datatype RSMPI_C_DOUBLE_COMPLEX;
 
 extern const MPI_Datatype RSMPI_INT8_T;
 extern const MPI_Datatype RSMPI_INT16_T;
@@ -21,8 +23,40 @@ extern const MPI_Datatype RSMPI_UINT16_T;
 extern const MPI_Datatype RSMPI_UINT32_T;
 extern const MPI_Datatype RSMPI_UINT64_T;
 
+extern const MPI_Datatype RSMPI_BYTE;
+
+extern const MPI_Datatype RSMPI_INTEGER;
+extern const MPI_Datatype RSMPI_REAL;
+extern const MPI_Datatype RSMPI_DOUBLE_PRECISION;
+extern const MPI_Datatype RSMPI_COMPLEX;
+extern const MPI_Datatype RSMPI_DOUBLE_COMPLEX;
+extern const MPI_Datatype RSMPI_LOGICAL;
+extern const MPI_Datatype RSMPI_CHARACTER;
+
+extern const MPI_Datatype RSMPI_FLOAT_INT;
+extern const MPI_Datatype RSMPI_DOUBLE_INT;
+extern const MPI_Datatype RSMPI_SHORT_INT;
+extern const MPI_Datatype RSMPI_2INT;
+
 extern const MPI_Datatype RSMPI_DATATYPE_NULL;
 
+extern const int RSMPI_ORDER_C;
+extern const int RSMPI_ORDER_FORTRAN;
+
+extern const int RSMPI_COMBINER_NAMED;
+extern const int RSMPI_COMBINER_DUP;
+extern const int RSMPI_COMBINER_CONTIGUOUS;
+extern const int RSMPI_COMBINER_VECTOR;
+extern const int RSMPI_COMBINER_HVECTOR;
+extern const int RSMPI_COMBINER_INDEXED;
+extern const int RSMPI_COMBINER_HINDEXED;
+extern const int RSMPI_COMBINER_INDEXED_BLOCK;
+extern const int RSMPI_COMBINER_HINDEXED_BLOCK;
+extern const int RSMPI_COMBINER_STRUCT;
+extern const int RSMPI_COMBINER_SUBARRAY;
+extern const int RSMPI_COMBINER_DARRAY;
+extern const int RSMPI_COMBINER_RESIZED;
+
 extern const MPI_Comm RSMPI_COMM_WORLD;
 extern const MPI_Comm RSMPI_COMM_NULL;
 extern const MPI_Comm RSMPI_COMM_SELF;
@@ -33,9 +67,13 @@ extern const MPI_Group RSMPI_GROUP_EMPTY;
 extern const MPI_Group RSMPI_GROUP_NULL;
 extern const int RSMPI_UNDEFINED;
 
+extern const int RSMPI_SUCCESS;
+
 extern const int RSMPI_PROC_NULL;
 extern const int RSMPI_ANY_SOURCE;
 extern const int RSMPI_ANY_TAG;
+extern const int RSMPI_ROOT;
+extern const int RSMPI_BSEND_OVERHEAD;
 
 extern const MPI_Message RSMPI_MESSAGE_NULL;
 extern const MPI_Message RSMPI_MESSAGE_NO_PROC;
@@ -45,6 +83,8 @@ extern const MPI_Request RSMPI_REQUEST_NULL;
 extern MPI_Status* const RSMPI_STATUS_IGNORE;
 extern MPI_Status* const RSMPI_STATUSES_IGNORE;
 
+extern int* const RSMPI_ERRCODES_IGNORE;
+
 extern const int RSMPI_IDENT;
 extern const int RSMPI_CONGRUENT;
 extern const int RSMPI_SIMILAR;
@@ -61,6 +101,10 @@ extern const int RSMPI_DIST_GRAPH;
 
 extern const int RSMPI_MAX_LIBRARY_VERSION_STRING;
 extern const int RSMPI_MAX_PROCESSOR_NAME;
+extern const int RSMPI_MAX_ERROR_STRING;
+
+extern const int RSMPI_TAG_UB;
+extern const int RSMPI_WTIME_IS_GLOBAL;
 
 extern const MPI_Op RSMPI_MAX;
 extern const MPI_Op RSMPI_MIN;
@@ -72,12 +116,18 @@ extern const MPI_Op RSMPI_LOR;
 extern const MPI_Op RSMPI_BOR;
 extern const MPI_Op RSMPI_LXOR;
 extern const MPI_Op RSMPI_BXOR;
+extern const MPI_Op RSMPI_MINLOC;
+extern const MPI_Op RSMPI_MAXLOC;
 
 extern const MPI_Errhandler RSMPI_ERRORS_ARE_FATAL;
 extern const MPI_Errhandler RSMPI_ERRORS_RETURN;
 
 extern const MPI_File RSMPI_FILE_NULL;
 
+extern const int RSMPI_MODE_RDONLY;
+extern const int RSMPI_MODE_WRONLY;
+extern const int RSMPI_MODE_CREATE;
+
 extern const MPI_Info RSMPI_INFO_NULL;
 
 extern const MPI_Win RSMPI_WIN_NULL;
@@ -2,6 +2,16 @@
 //!
 //! Developing...
 //!
+//! # Single process communicators
+//!
+//! With the `single-process-shortcuts` feature, `all_gather_into()`, `all_reduce_into()` and
+//! `broadcast_into()` do not call into MPI on an intra-communicator that contains only the calling
+//! process. The former two copy the send buffer into the receive buffer directly if both are
+//! described by the same predefined datatype and count, the latter has nothing to do. This makes
+//! single process runs, e.g. for debugging and tests, cheaper without changing the results. Tools
+//! that intercept MPI calls do not see the skipped collectives. Without the feature, the
+//! collectives always call into MPI.
+//!
 //! # Unfinished features
 //!
 //! - **5.8**: All-to-all, `MPI_Alltoallw()`
@@ -9,25 +19,40 @@
 //! - **5.12**: Nonblocking collective operations,
 //! `MPI_Ialltoallw()`, `MPI_Ireduce_scatter()`
 
-#[cfg(feature = "user-operations")]
-use std::mem;
-use std::os::raw::{c_int, c_void};
-use std::{fmt, ptr};
+use std::collections::hash_map::RandomState;
+use std::ffi::{CString, OsStr};
+use std::hash::{BuildHasher, Hasher};
+use std::mem::{self, MaybeUninit};
+use std::os::raw::{c_char, c_int, c_void};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{error, fmt, iter, ptr, slice};
+
+use conv::ConvUtil;
 
 #[cfg(feature = "user-operations")]
 use libffi::middle::{Cif, Closure, Type};
 
 use crate::ffi;
-use crate::ffi::MPI_Op;
+use crate::ffi::{MPI_Comm, MPI_Datatype, MPI_Op};
+// The large count variants of MPI-4, or their classic counterparts without the `mpi4` feature
+#[cfg(not(feature = "mpi4"))]
+use crate::ffi::{MPI_Allreduce as MPI_Allreduce_c, MPI_Bcast as MPI_Bcast_c};
+#[cfg(feature = "mpi4")]
+use crate::ffi::{MPI_Allreduce_c, MPI_Bcast_c};
 
 use crate::datatype::traits::*;
+use crate::datatype::{assert_not_zero_sized, DatatypeRef, PartitionMut, SystemDatatype};
 #[cfg(feature = "user-operations")]
-use crate::datatype::{DatatypeRef, DynBuffer, DynBufferMut};
+use crate::datatype::{DynBuffer, DynBufferMut};
+use crate::environment::{self, Error};
 use crate::raw::traits::*;
+#[cfg(feature = "mpi4")]
+use crate::request::PersistentRequest;
 use crate::request::{Request, Scope, StaticScope};
 use crate::topology::traits::*;
-use crate::topology::{Process, Rank};
-use crate::with_uninitialized;
+use crate::topology::{InterCommunicator, Process, Rank};
+use crate::{with_uninitialized, with_uninitialized2, Count};
 
 /// Collective communication traits
 pub mod traits {
@@ -56,6 +81,31 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Barrier synchronization that measures the time spent waiting
+    ///
+    /// Enters a barrier like `barrier()` and measures the time spent inside it using
+    /// `mpi::time()`. Returns the longest time in seconds that any process in the `Communicator`
+    /// `&self` waited, i.e. how far ahead of the slowest process the fastest process was. This is
+    /// useful as a diagnostic for load imbalance. The maximum is determined with an additional
+    /// all-reduce operation after the barrier.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/barrier.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.3, 5.9.6
+    fn timed_barrier(&self) -> f64 {
+        let start = crate::time();
+        self.barrier();
+        let waited = crate::time() - start;
+
+        let mut max_waited = 0.0;
+        self.all_reduce_into(&waited, &mut max_waited, SystemOperation::max());
+        max_waited
+    }
+
     /// Gather contents of buffers on all participating processes.
     ///
     /// After the call completes, the contents of the send `Buffer`s on all processes will be
@@ -75,6 +125,9 @@ pub trait CommunicatorCollectives: Communicator {
         S: Buffer,
         R: BufferMut,
     {
+        if is_self_only(self) && copy_predefined(sendbuf, recvbuf) {
+            return;
+        }
         unsafe {
             ffi::MPI_Allgather(
                 sendbuf.pointer(),
@@ -122,6 +175,132 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Gather the processor names of all processes on all processes.
+    ///
+    /// Returns a vector that maps the rank of every process in this communicator to the name of
+    /// the processor (usually the host name) it is running on, see
+    /// `environment::processor_name()`. Processes with the same name share a node, which can be
+    /// used to group processes by node. Names that are not valid UTF-8 are converted lossily.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/host_map.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.7, 8.1.1
+    fn gather_host_map(&self) -> Vec<String> {
+        let name = environment::processor_name()
+            .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+        let name = name.as_bytes();
+
+        let size = self.size().value_as().unwrap();
+        let mut lengths: Vec<Count> = vec![0; size];
+        self.all_gather_into(&name.count(), &mut lengths[..]);
+
+        let displs: Vec<Count> = lengths
+            .iter()
+            .scan(0, |offset, &length| {
+                let displ = *offset;
+                *offset += length;
+                Some(displ)
+            })
+            .collect();
+        let total = lengths.iter().sum::<Count>().value_as().unwrap();
+        let mut names = vec![0u8; total];
+        {
+            let mut partition = PartitionMut::new(&mut names[..], &lengths[..], &displs[..]);
+            self.all_gather_varcount_into(name, &mut partition);
+        }
+
+        lengths
+            .iter()
+            .zip(displs.iter())
+            .map(|(&length, &displ)| {
+                let start: usize = displ.value_as().unwrap();
+                let end = start + length.value_as::<usize>().unwrap();
+                String::from_utf8_lossy(&names[start..end]).into_owned()
+            })
+            .collect()
+    }
+
+    /// The rank of the calling process among the processes of this communicator that run on the
+    /// same node.
+    ///
+    /// Nodes are determined by splitting the communicator into groups of processes that can share
+    /// memory, see `Communicator::split_shared()`. The processes on a node are ranked in the order
+    /// of their ranks in this communicator, so the process with node rank `0` is the one with the
+    /// lowest rank on its node, which makes it a natural owner of node-local resources.
+    ///
+    /// This is a collective operation, all processes of the communicator have to call it.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/host_map.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2
+    fn node_rank(&self) -> Rank {
+        self.split_shared(self.rank()).rank()
+    }
+
+    /// The number of nodes the processes of this communicator run on.
+    ///
+    /// Nodes are determined like in `node_rank()`. Together with the size of the node-local
+    /// communicator, this allows e.g. deciding how many threads each process should spawn.
+    ///
+    /// This is a collective operation, all processes of the communicator have to call it.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/host_map.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6, 6.4.2
+    fn node_count(&self) -> Rank {
+        let is_leader: Rank = if self.node_rank() == 0 { 1 } else { 0 };
+        self.all_reduce(&is_leader, SystemOperation::sum())
+    }
+
+    /// Agree on a common random seed across all processes.
+    ///
+    /// The process with rank `root` draws a seed from the randomness of the operating system and
+    /// the current time and broadcasts it, so all processes return the same value. Seeding every
+    /// process from the clock independently instead easily yields identical seeds on processes
+    /// started at the same time.
+    ///
+    /// To give every process an independent random stream while keeping runs reproducible from a
+    /// single base seed, combine the returned seed with the rank via `seed_for_rank()`. Print or
+    /// store the base seed and pass it to `Root::broadcast()` instead to repeat a run.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/broadcast_seed.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast_seed(&self, root: Rank) -> u64
+    where
+        Self: Sized,
+    {
+        let mut seed = 0u64;
+        if self.rank() == root {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u128(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_nanos()),
+            );
+            hasher.write_u32(std::process::id());
+            seed = hasher.finish();
+        }
+        self.process_at_rank(root).broadcast(&mut seed);
+        seed
+    }
+
     /// Distribute the send `Buffer`s from all processes to the receive `Buffer`s on all processes.
     ///
     /// Each process sends and receives the same count of elements to and from each process.
@@ -152,6 +331,46 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Exchange one value with every process.
+    ///
+    /// `send` contains one value per process of the communicator, `send[r]` is sent to the
+    /// process with rank `r`. Returns one value per process, the value at index `r` has been
+    /// received from the process with rank `r`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_to_all.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.8
+    fn all_to_all_vec<T>(&self, send: &[T]) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        let size = self.size() as usize;
+        assert_eq!(
+            size,
+            send.len(),
+            "all_to_all_vec needs exactly one value per process."
+        );
+        let mut recv: Vec<T> = Vec::with_capacity(size);
+        unsafe {
+            ffi::MPI_Alltoall(
+                send.as_ptr() as _,
+                1,
+                T::equivalent_datatype().as_raw(),
+                recv.as_mut_ptr() as _,
+                1,
+                T::equivalent_datatype().as_raw(),
+                self.as_raw(),
+            );
+            // MPI has written exactly one instance of `T` per process to `recv`.
+            recv.set_len(size);
+        }
+        recv
+    }
+
     /// Distribute the send `Buffer`s from all processes to the receive `Buffer`s on all processes.
     ///
     /// The count of elements to send and receive to and from each process can vary and is specified
@@ -196,7 +415,132 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
         O: Operation,
     {
+        check_operation(&op, sendbuf.as_datatype().as_raw());
+        // The reduction of a single contribution is the contribution itself, whatever `op` is.
+        if is_self_only(self) && copy_predefined(sendbuf, recvbuf) {
+            return;
+        }
+        unsafe {
+            ffi::MPI_Allreduce(
+                sendbuf.pointer(),
+                recvbuf.pointer_mut(),
+                sendbuf.count(),
+                sendbuf.as_datatype().as_raw(),
+                op.as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Performs a global reduction under the operation `op` of `count` instances of `datatype` in
+    /// `sendbuf` and stores the result in `recvbuf` on all processes.
+    ///
+    /// Unlike `all_reduce_into()`, the datatype is not derived from the element type of the
+    /// buffers, e.g. to reduce structs described by a committed user datatype with a user
+    /// operation.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Safety
+    /// - `count` instances of `datatype` must map both `sendbuf` and `recvbuf` without exposing
+    ///   any padding bytes or exceeding the bounds of the objects.
+    /// - `op` must be defined for `datatype`, predefined operations are only defined for
+    ///   predefined datatypes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6
+    unsafe fn all_reduce_into_with_datatype<S: ?Sized, R: ?Sized, D, O>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+        count: Count,
+        datatype: &D,
+        op: O,
+    ) where
+        S: Pointer,
+        R: PointerMut,
+        D: Datatype,
+        O: Operation,
+    {
+        check_operation(&op, datatype.as_raw());
+        ffi::MPI_Allreduce(
+            sendbuf.pointer(),
+            recvbuf.pointer_mut(),
+            count,
+            datatype.as_raw(),
+            op.as_raw(),
+            self.as_raw(),
+        );
+    }
+
+    /// Performs a global reduction under the operation `op` of a single value from every process
+    /// and returns the result on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6
+    fn all_reduce<T, O>(&self, value: &T, op: O) -> T
+    where
+        T: Equivalence,
+        O: Operation,
+    {
+        check_operation(&op, T::equivalent_datatype().as_raw());
         unsafe {
+            with_uninitialized(|result| {
+                ffi::MPI_Allreduce(
+                    value.pointer(),
+                    result as _,
+                    1,
+                    T::equivalent_datatype().as_raw(),
+                    op.as_raw(),
+                    self.as_raw(),
+                )
+            })
+            .1
+        }
+    }
+
+    /// Performs a global reduction like `all_reduce_into()` and agrees on its outcome across all
+    /// processes.
+    ///
+    /// With the default `ErrorHandler::Fatal`, any error aborts the job and this behaves like
+    /// `all_reduce_into()`. With `ErrorHandler::Return` set on this communicator, an error may be
+    /// reported on some processes only, which then take a different code path than the others and
+    /// desynchronize the job. This method therefore follows the reduction with a second reduction
+    /// of the error codes of all processes, so that either all processes return `Ok(())` or all
+    /// processes return the same `CollectiveError`, naming the lowest rank that failed and its
+    /// error.
+    ///
+    /// If the second reduction fails as well, the local error is returned.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6, 8.3
+    fn all_reduce_checked<S: ?Sized, R: ?Sized, O>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+        op: O,
+    ) -> Result<(), CollectiveError>
+    where
+        S: Buffer,
+        R: BufferMut,
+        O: Operation,
+    {
+        let success = unsafe { ffi::RSMPI_SUCCESS };
+        let rank = self.rank();
+        let mut code = unsafe {
             ffi::MPI_Allreduce(
                 sendbuf.pointer(),
                 recvbuf.pointer_mut(),
@@ -204,8 +548,189 @@ pub trait CommunicatorCollectives: Communicator {
                 sendbuf.as_datatype().as_raw(),
                 op.as_raw(),
                 self.as_raw(),
+            )
+        };
+        // Reports the local error, or the error of the check itself if there is none.
+        let local_error = |code, check| CollectiveError {
+            rank,
+            error: Error::from_code(if code == success { check } else { code }),
+        };
+
+        // MAXLOC picks the lowest rank among the failed processes.
+        let failed = ValueIndex::new(if code == success { 0 } else { 1 }, rank);
+        let (check, first_failed) = unsafe {
+            with_uninitialized(|first_failed: *mut ValueIndex<c_int>| {
+                ffi::MPI_Allreduce(
+                    failed.pointer(),
+                    first_failed as _,
+                    1,
+                    ValueIndex::<c_int>::equivalent_datatype().as_raw(),
+                    ffi::RSMPI_MAXLOC,
+                    self.as_raw(),
+                )
+            })
+        };
+        if check != success {
+            return Err(local_error(code, check));
+        }
+        if first_failed.value == 0 {
+            return Ok(());
+        }
+
+        let check = unsafe {
+            ffi::MPI_Bcast(
+                &mut code as *mut c_int as _,
+                1,
+                c_int::equivalent_datatype().as_raw(),
+                first_failed.index,
+                self.as_raw(),
+            )
+        };
+        if check != success {
+            return Err(local_error(code, check));
+        }
+        Err(CollectiveError {
+            rank: first_failed.index,
+            error: Error::from_code(code),
+        })
+    }
+
+    /// Performs a global reduction like `all_reduce_into()`, checking first that all processes
+    /// pass consistent arguments.
+    ///
+    /// Processes that disagree on the count, the datatype or the operation of a reduction may
+    /// hang or silently compute garbage. In debug builds, this method compares the count, the size
+    /// and extent of the datatype and the operation across all processes with an additional
+    /// reduction before the actual one. User-defined operations cannot be told apart, only
+    /// whether the operation is user-defined or which predefined operation it is is checked. In
+    /// release builds, this is `all_reduce_into()` without any overhead.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, on all processes if any argument differs between processes, naming the
+    /// argument
+    ///
+    /// # Examples
+    ///
+    /// See `examples/validated_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6
+    fn all_reduce_validated_into<S: ?Sized, R: ?Sized, O>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+        op: O,
+    ) where
+        S: Buffer,
+        R: BufferMut,
+        O: Operation,
+    {
+        if cfg!(debug_assertions) {
+            let (size, extent) = datatype_size_and_extent(sendbuf.as_datatype().as_raw());
+            check_consistent_arguments(
+                self.as_raw(),
+                "all_reduce_validated_into",
+                &[
+                    ("count", i64::from(sendbuf.count())),
+                    ("datatype size", size),
+                    ("datatype extent", extent),
+                    ("operation", operation_id(op.as_raw())),
+                ],
             );
         }
+        self.all_reduce_into(sendbuf, recvbuf, op)
+    }
+
+    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
+    /// stores the result in `recvbuf` on all processes, supporting slices whose length exceeds
+    /// the range of `Count`.
+    ///
+    /// With the `mpi4` feature, this uses the large count variant `MPI_Allreduce_c()` of MPI-4.
+    /// Otherwise it falls back to `MPI_Allreduce()`.
+    ///
+    /// # Panics
+    ///
+    /// - if `sendbuf` and `recvbuf` differ in length
+    /// - if the length does not fit into `Count` and the `mpi4` feature is not enabled
+    /// - if `T` is a zero-sized type
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6
+    fn all_reduce_into_c<T, O>(&self, sendbuf: &[T], recvbuf: &mut [T], op: O)
+    where
+        T: Equivalence,
+        O: Operation,
+    {
+        assert_eq!(
+            sendbuf.len(),
+            recvbuf.len(),
+            "'sendbuf' and 'recvbuf' must be the same length"
+        );
+        check_operation(&op, T::equivalent_datatype().as_raw());
+        unsafe {
+            MPI_Allreduce_c(
+                sendbuf.as_ptr() as _,
+                recvbuf.as_mut_ptr() as _,
+                large_count::<T>(sendbuf.len()),
+                T::equivalent_datatype().as_raw(),
+                op.as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
+    /// Performs a global reduction with bit-for-bit reproducible results and stores the result in
+    /// `recvbuf` on all processes.
+    ///
+    /// MPI may combine the contributions of the processes in any order, which for floating-point
+    /// operations like addition can change the result depending on the algorithm chosen by the
+    /// MPI library. This method instead gathers all contributions on rank 0, combines them
+    /// element-wise in rank order, i.e. `op(op(op(x_0, x_1), x_2), ...)`, and broadcasts the result.
+    /// For a fixed number of processes, the result therefore only depends on the input data.
+    ///
+    /// This is considerably slower than `all_reduce_into()` and needs memory for the
+    /// contributions of all processes on rank 0. Only use it where reproducibility matters.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4, 5.5
+    fn all_reduce_deterministic_into<T, F>(&self, sendbuf: &[T], recvbuf: &mut [T], op: F)
+    where
+        T: Equivalence + Copy,
+        F: Fn(T, T) -> T,
+        Self: Sized,
+    {
+        assert_eq!(
+            sendbuf.len(),
+            recvbuf.len(),
+            "Send and receive buffers of a reduction must have the same length."
+        );
+        let root_process = self.process_at_rank(0);
+        if self.rank() == 0 {
+            let mut contributions = sendbuf.repeat(self.size() as usize);
+            root_process.gather_into_root(sendbuf, &mut contributions[..]);
+
+            recvbuf.copy_from_slice(sendbuf);
+            for contribution in contributions.chunks(sendbuf.len().max(1)).skip(1) {
+                for (r, &c) in recvbuf.iter_mut().zip(contribution) {
+                    *r = op(*r, c);
+                }
+            }
+        } else {
+            root_process.gather_into(sendbuf);
+        }
+        root_process.broadcast_into(recvbuf);
     }
 
     /// Performs an element-wise global reduction under the operation `op` of the input data in
@@ -230,6 +755,7 @@ pub trait CommunicatorCollectives: Communicator {
         O: Operation,
     {
         assert_eq!(recvbuf.count() * self.size(), sendbuf.count());
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             ffi::MPI_Reduce_scatter_block(
                 sendbuf.pointer(),
@@ -258,6 +784,7 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
         O: Operation,
     {
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             ffi::MPI_Scan(
                 sendbuf.pointer(),
@@ -286,6 +813,7 @@ pub trait CommunicatorCollectives: Communicator {
         R: BufferMut,
         O: Operation,
     {
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             ffi::MPI_Exscan(
                 sendbuf.pointer(),
@@ -302,10 +830,12 @@ pub trait CommunicatorCollectives: Communicator {
     ///
     /// Calling processes (or threads within the calling processes) enter the barrier. Completion
     /// methods on the associated request object will block until all processes have entered.
+    /// Poll the request with `test()` to keep doing other work, e.g. receiving messages, until
+    /// the barrier completes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_barrier.rs`
+    /// See `examples/immediate_barrier.rs`, `examples/termination_detection.rs`
     ///
     /// # Standard section(s)
     ///
@@ -446,6 +976,15 @@ pub trait CommunicatorCollectives: Communicator {
 
     /// Initiate non-blocking all-to-all communication.
     ///
+    /// The send and receive `Buffer`s may contain different counts of elements for every process.
+    /// Their distribution is specified via `Partitioned`. Both partitions, including their counts
+    /// and displacements which MPI reads while the operation is in progress, stay borrowed until
+    /// the returned request has completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_all_to_all_varcount.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 5.12.6
@@ -505,6 +1044,7 @@ pub trait CommunicatorCollectives: Communicator {
         O: 'a + Operation,
         Sc: Scope<'a>,
     {
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -524,6 +1064,63 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Creates a persistent global reduction under the operation `op` of the input data in
+    /// `sendbuf` that stores the result in `recvbuf` on all processes.
+    ///
+    /// The returned request is inactive. Every `PersistentRequest::start()` performs the reduction
+    /// of the current contents of `sendbuf`, which saves the set-up cost of the operation when the
+    /// same reduction is repeated many times, e.g. in every iteration of an iterative solver. All
+    /// processes have to start and complete the request the same number of times.
+    ///
+    /// `sendbuf` is borrowed mutably, so that it can be updated through
+    /// `PersistentRequest::buffers_mut()` between the completion of one reduction and the start of
+    /// the next.
+    ///
+    /// Requires an implementation of MPI-4 and the `mpi4` feature.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_all_reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.13.8 (MPI-4)
+    #[cfg(feature = "mpi4")]
+    fn all_reduce_init<'a, Sc, S: ?Sized, R: ?Sized, O>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a mut S,
+        recvbuf: &'a mut R,
+        op: O,
+    ) -> PersistentRequest<'a, (&'a mut S, &'a mut R), Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        O: 'a + Operation,
+        Sc: Scope<'a>,
+    {
+        check_operation(&op, sendbuf.as_datatype().as_raw());
+        unsafe {
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Allreduce_init(
+                        sendbuf.pointer(),
+                        recvbuf.pointer_mut(),
+                        sendbuf.count(),
+                        sendbuf.as_datatype().as_raw(),
+                        op.as_raw(),
+                        self.as_raw(),
+                        ffi::RSMPI_INFO_NULL,
+                        request,
+                    )
+                })
+                .1,
+                (sendbuf, recvbuf),
+                scope,
+            )
+        }
+    }
+
     /// Initiates a non-blocking element-wise global reduction under the operation `op` of the
     /// input data in `sendbuf` and scatters the result into equal sized blocks in the receive
     /// buffers on all processes.
@@ -549,6 +1146,7 @@ pub trait CommunicatorCollectives: Communicator {
         Sc: Scope<'a>,
     {
         assert_eq!(recvbuf.count() * self.size(), sendbuf.count());
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -591,6 +1189,7 @@ pub trait CommunicatorCollectives: Communicator {
         O: 'a + Operation,
         Sc: Scope<'a>,
     {
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -633,6 +1232,7 @@ pub trait CommunicatorCollectives: Communicator {
         O: 'a + Operation,
         Sc: Scope<'a>,
     {
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -679,6 +1279,10 @@ pub trait Root: AsCommunicator {
     where
         Buf: BufferMut,
     {
+        // The only process is the root, its buffer already has the right contents.
+        if is_self_only(self.as_communicator()) {
+            return;
+        }
         unsafe {
             ffi::MPI_Bcast(
                 buffer.pointer_mut(),
@@ -690,6 +1294,142 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Broadcast of the contents of a buffer like `broadcast_into()`, checking first that all
+    /// processes pass consistent arguments.
+    ///
+    /// In debug builds, this method compares the rank of the root and the size of the buffer in
+    /// bytes across all processes with an additional reduction before the broadcast. In release
+    /// builds, this is `broadcast_into()` without any overhead.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, on all processes if any argument differs between processes, naming the
+    /// argument
+    ///
+    /// # Examples
+    ///
+    /// See `examples/validated_collectives.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast_validated_into<Buf: ?Sized>(&self, buffer: &mut Buf)
+    where
+        Buf: BufferMut,
+    {
+        if cfg!(debug_assertions) {
+            let (size, _) = datatype_size_and_extent(buffer.as_datatype().as_raw());
+            check_consistent_arguments(
+                self.as_communicator().as_raw(),
+                "broadcast_validated_into",
+                &[
+                    ("root rank", i64::from(self.root_rank())),
+                    ("buffer size in bytes", i64::from(buffer.count()) * size),
+                ],
+            );
+        }
+        self.broadcast_into(buffer)
+    }
+
+    /// Broadcast of the contents of a slice, supporting slices whose length exceeds the range of
+    /// `Count`.
+    ///
+    /// With the `mpi4` feature, this uses the large count variant `MPI_Bcast_c()` of MPI-4.
+    /// Otherwise it falls back to `MPI_Bcast()`.
+    ///
+    /// # Panics
+    ///
+    /// If the length does not fit into `Count` and the `mpi4` feature is not enabled, or if `T` is a
+    /// zero-sized type
+    ///
+    /// # Examples
+    ///
+    /// See `examples/broadcast.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast_into_c<T>(&self, buffer: &mut [T])
+    where
+        T: Equivalence,
+    {
+        unsafe {
+            MPI_Bcast_c(
+                buffer.as_mut_ptr() as _,
+                large_count::<T>(buffer.len()),
+                T::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Broadcast of a single value
+    ///
+    /// After the call completes, `value` on all processes in the `Communicator` of the `Root`
+    /// `&self` will contain what it contains on the `Root`. Unlike `broadcast_into()` this only
+    /// accepts single values, not buffers.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/broadcast.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast<T>(&self, value: &mut T)
+    where
+        T: Equivalence,
+    {
+        self.broadcast_into(value)
+    }
+
+    /// Broadcast of a `Vec` whose length is only known on the `Root`
+    ///
+    /// Broadcasts the length of `data` on the `Root` first, then its contents. On all other
+    /// processes, the previous contents of `data` are replaced by the contents on the `Root`, the
+    /// `Vec` grows as needed.
+    ///
+    /// # Panics
+    ///
+    /// If the length does not fit into `Count` and the `mpi4` feature is not enabled, or if `T` is a
+    /// zero-sized type
+    ///
+    /// # Examples
+    ///
+    /// See `examples/broadcast.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast_vec<T>(&self, data: &mut Vec<T>)
+    where
+        T: Equivalence,
+    {
+        let is_root = self.as_communicator().rank() == self.root_rank();
+        let mut len = data.len() as u64;
+        self.broadcast_into(&mut len);
+        let len = len
+            .value_as()
+            .expect("Length of broadcast vector cannot be expressed as a usize.");
+        if !is_root {
+            data.clear();
+            data.reserve_exact(len);
+        }
+        unsafe {
+            MPI_Bcast_c(
+                data.as_mut_ptr() as _,
+                large_count::<T>(len),
+                T::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+            // MPI_Bcast has written `len` instances of `T` to `data` on all non-root processes,
+            // the root already held them.
+            data.set_len(len);
+        }
+    }
+
     /// Gather contents of buffers on `Root`.
     ///
     /// After the call completes, the contents of the `Buffer`s on all ranks will be
@@ -732,7 +1472,15 @@ pub trait Root: AsCommunicator {
     ///
     /// All send `Buffer`s must have the same count of elements.
     ///
-    /// This function must be called on the root process.
+    /// This function must be called on the root process. The other processes do not need a
+    /// receive buffer and call `gather_into()` instead.
+    ///
+    /// # Panics
+    ///
+    /// - if called on a non-root process
+    /// - if the receive `Buffer` cannot hold the contributions of all processes, i.e. if it does
+    /// not contain `size` times the count of the send `Buffer` when both are described by the same
+    /// datatype, or a multiple of `size` elements otherwise
     ///
     /// # Examples
     ///
@@ -747,8 +1495,34 @@ pub trait Root: AsCommunicator {
         R: BufferMut,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let size = self.as_communicator().size();
+        if sendbuf.as_datatype().as_raw() == recvbuf.as_datatype().as_raw() {
+            // computed in `usize`, the product of two `Count`s may exceed the range of `Count`
+            let expected = size
+                .value_as::<usize>()
+                .unwrap()
+                .checked_mul(sendbuf.count().value_as().unwrap())
+                .expect("Total count of gathered elements cannot be expressed as a usize.");
+            assert_eq!(
+                expected,
+                recvbuf.count().value_as::<usize>().unwrap(),
+                "gather root buffer must hold size*count elements, got {} expected {} ({} * {})",
+                recvbuf.count(),
+                expected,
+                size,
+                sendbuf.count()
+            );
+        } else {
+            assert_eq!(
+                0,
+                recvbuf.count() % size,
+                "gather root buffer must hold a multiple of size elements, got {} for size {}",
+                recvbuf.count(),
+                size
+            );
+        }
         unsafe {
-            let recvcount = recvbuf.count() / self.as_communicator().size();
+            let recvcount = recvbuf.count() / size;
             ffi::MPI_Gather(
                 sendbuf.pointer(),
                 sendbuf.count(),
@@ -762,6 +1536,49 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Gather the contents of `local` from all processes on `Root`.
+    ///
+    /// Returns `Some(gathered)` on the `Root`, the concatenation of `local` from all processes in
+    /// rank order, and `None` on all other processes. All processes must pass slices of the same
+    /// length. This function must be called on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather<T>(&self, local: &[T]) -> Option<Vec<T>>
+    where
+        T: Equivalence,
+    {
+        if self.as_communicator().rank() != self.root_rank() {
+            self.gather_into(local);
+            return None;
+        }
+        let len = local
+            .len()
+            .checked_mul(self.as_communicator().size().value_as().unwrap())
+            .expect("Total count of gathered elements cannot be expressed as a usize.");
+        let mut gathered: Vec<T> = Vec::with_capacity(len);
+        unsafe {
+            ffi::MPI_Gather(
+                local.pointer(),
+                local.count(),
+                T::equivalent_datatype().as_raw(),
+                gathered.as_mut_ptr() as _,
+                local.count(),
+                T::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+            // MPI_Gather has filled the contributions of all processes.
+            gathered.set_len(len);
+        }
+        Some(gathered)
+    }
+
     /// Gather contents of buffers on `Root`.
     ///
     /// After the call completes, the contents of the `Buffer`s on all ranks will be
@@ -837,6 +1654,98 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Gather contents of buffers on `Root` into uninitialized memory.
+    ///
+    /// Like `gather_varcount_into_root()`, but the contributions are received into `recvbuf`
+    /// without having to initialize it first. The contribution of process `r` consists of
+    /// `counts[r]` instances of `T`, which are stored one after the other at the start of
+    /// `recvbuf`. Returns the contributions of all processes in the order of their ranks.
+    ///
+    /// This function must be called on the root process, the other processes call
+    /// `gather_varcount_into()`.
+    ///
+    /// # Panics
+    ///
+    /// - if `counts` does not have an entry for every process
+    /// - if a count is negative
+    /// - if `recvbuf` is shorter than the sum of `counts`
+    /// - if `sendbuf` on the root does not contain `counts[root]` elements
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather_varcount.rs`
+    ///
+    /// # Safety
+    ///
+    /// Every process `r` must send exactly `counts[r]` instances of `T`, i.e. a send buffer with
+    /// the same type signature. MPI does not report a process that sends less, the remainder of
+    /// its contribution would be returned uninitialized.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    unsafe fn gather_varcount_into_root_uninit<'b, S: ?Sized, T>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &'b mut [MaybeUninit<T>],
+        counts: &[Count],
+    ) -> Vec<&'b mut [T]>
+    where
+        S: Buffer,
+        T: Equivalence,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        assert_eq!(
+            counts.count(),
+            self.as_communicator().size(),
+            "Counts must have an entry for every process."
+        );
+        assert_eq!(
+            sendbuf.count(),
+            counts[self.root_rank().value_as::<usize>().unwrap()],
+            "The root must contribute as many elements as its entry in counts."
+        );
+        let lengths: Vec<usize> = counts
+            .iter()
+            .map(|&count| {
+                count
+                    .value_as()
+                    .expect("Counts of a gather must not be negative.")
+            })
+            .collect();
+        let total: usize = lengths.iter().sum();
+        assert!(
+            total <= recvbuf.len(),
+            "Receive buffer of length {} is too short for {} elements.",
+            recvbuf.len(),
+            total
+        );
+        let displs = displacements(counts);
+        ffi::MPI_Gatherv(
+            sendbuf.pointer(),
+            sendbuf.count(),
+            sendbuf.as_datatype().as_raw(),
+            recvbuf.as_mut_ptr() as _,
+            counts.as_ptr(),
+            displs.as_ptr(),
+            T::equivalent_datatype().as_raw(),
+            self.root_rank(),
+            self.as_communicator().as_raw(),
+        );
+        // Every rank `r` has sent `counts[r]` instances of `T`, so MPI has written `total`
+        // instances in a row to the start of `recvbuf`, and since `T: Equivalence` their bit
+        // patterns are valid values of `T`.
+        let mut rest = slice::from_raw_parts_mut(recvbuf.as_mut_ptr() as *mut T, total);
+        lengths
+            .into_iter()
+            .map(|len| {
+                let (contribution, tail) = mem::take(&mut rest).split_at_mut(len);
+                rest = tail;
+                contribution
+            })
+            .collect()
+    }
+
     /// Scatter contents of a buffer on the root process to all processes.
     ///
     /// After the call completes each participating process will have received a part of the send
@@ -888,25 +1797,76 @@ pub trait Root: AsCommunicator {
     /// # Standard section(s)
     ///
     /// 5.6
-    fn scatter_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    fn scatter_into_root<S: ?Sized, R: ?Sized>(&self, sendbuf: &S, recvbuf: &mut R)
+    where
+        S: Buffer,
+        R: BufferMut,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let sendcount = sendbuf.count() / self.as_communicator().size();
+        unsafe {
+            ffi::MPI_Scatter(
+                sendbuf.pointer(),
+                sendcount,
+                sendbuf.as_datatype().as_raw(),
+                recvbuf.pointer_mut(),
+                recvbuf.count(),
+                recvbuf.as_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Scatter a single value per process from the root process to all processes.
+    ///
+    /// `send` must be `Some` on the `Root` `&self`, holding one value per process in rank order,
+    /// and `None` on all other processes. Returns the value for the calling process.
+    ///
+    /// # Panics
+    ///
+    /// - if `send` is `Some` on a non-root process or `None` on the root process
+    /// - if the length of `send` differs from the size of the communicator
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter<T>(&self, send: Option<&[T]>) -> T
     where
-        S: Buffer,
-        R: BufferMut,
+        T: Equivalence,
     {
-        assert_eq!(self.as_communicator().rank(), self.root_rank());
-        let sendcount = sendbuf.count() / self.as_communicator().size();
-        unsafe {
-            ffi::MPI_Scatter(
-                sendbuf.pointer(),
-                sendcount,
-                sendbuf.as_datatype().as_raw(),
-                recvbuf.pointer_mut(),
-                recvbuf.count(),
-                recvbuf.as_datatype().as_raw(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
+        let comm = self.as_communicator();
+        assert_eq!(
+            comm.rank() == self.root_rank(),
+            send.is_some(),
+            "The values to scatter must be passed on the root process and only there."
+        );
+        if let Some(send) = send {
+            assert_eq!(
+                comm.size() as usize,
+                send.len(),
+                "There must be exactly one value to scatter per process."
             );
         }
+        unsafe {
+            with_uninitialized(|value| {
+                ffi::MPI_Scatter(
+                    send.map_or(ptr::null(), |send| send.as_ptr() as *const c_void),
+                    1,
+                    T::equivalent_datatype().as_raw(),
+                    value as _,
+                    1,
+                    T::equivalent_datatype().as_raw(),
+                    self.root_rank(),
+                    comm.as_raw(),
+                )
+            })
+            .1
+        }
     }
 
     /// Scatter contents of a buffer on the root process to all processes.
@@ -984,6 +1944,121 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Distribute a slice evenly among all processes.
+    ///
+    /// The slice `global` on the `Root` `&self` is split into contiguous chunks, one per process
+    /// in rank order, and every process receives its chunk. The chunks differ in length by at
+    /// most one element, the first `global.len() % size` processes receive one element more than
+    /// the others.
+    ///
+    /// `global` must be `Some` on the `Root` and `None` on all other processes. Returns the chunk
+    /// of the calling process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_slice.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4, 5.6
+    fn scatter_slice<T>(&self, global: Option<&[T]>) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        let comm = self.as_communicator();
+        assert_eq!(
+            comm.rank() == self.root_rank(),
+            global.is_some(),
+            "The slice to scatter must be passed on the root process and only there."
+        );
+
+        let mut len: Count = global.map_or(0, |global| {
+            global
+                .len()
+                .value_as()
+                .expect("Length of slice cannot be expressed as an MPI Count.")
+        });
+        self.broadcast(&mut len);
+
+        let counts = balanced_counts(len, comm.size());
+        let displs = displacements(&counts);
+        let count = counts[comm.rank() as usize];
+        let mut chunk: Vec<T> = Vec::with_capacity(count as usize);
+        unsafe {
+            ffi::MPI_Scatterv(
+                global.map_or(ptr::null(), |global| global.as_ptr() as *const c_void),
+                counts.as_ptr(),
+                displs.as_ptr(),
+                T::equivalent_datatype().as_raw(),
+                chunk.as_mut_ptr() as _,
+                count,
+                T::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                comm.as_raw(),
+            );
+            // MPI has written exactly `count` instances of `T` to `chunk`.
+            chunk.set_len(count as usize);
+        }
+        chunk
+    }
+
+    /// Reassemble a slice that is distributed among all processes.
+    ///
+    /// The counterpart of `scatter_slice()`: the slices `local` of all processes are concatenated
+    /// in rank order on the `Root` `&self`. The slices may have different lengths.
+    ///
+    /// Returns the concatenated slices on the `Root` and `None` on all other processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_slice.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_slice<T>(&self, local: &[T]) -> Option<Vec<T>>
+    where
+        T: Equivalence,
+    {
+        let comm = self.as_communicator();
+        let count: Count = local
+            .len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.");
+
+        if comm.rank() != self.root_rank() {
+            self.gather_into(&count);
+            self.gather_varcount_into(local);
+            return None;
+        }
+
+        let mut counts = vec![0; comm.size() as usize];
+        self.gather_into_root(&count, &mut counts[..]);
+        let displs = displacements(&counts);
+        let len: usize = counts
+            .iter()
+            .sum::<Count>()
+            .value_as()
+            .expect("Length of gathered slice cannot be expressed as a usize.");
+        let mut global: Vec<T> = Vec::with_capacity(len);
+        unsafe {
+            ffi::MPI_Gatherv(
+                local.as_ptr() as _,
+                count,
+                T::equivalent_datatype().as_raw(),
+                global.as_mut_ptr() as _,
+                counts.as_ptr(),
+                displs.as_ptr(),
+                T::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                comm.as_raw(),
+            );
+            // MPI has written exactly `len` instances of `T` to `global`.
+            global.set_len(len);
+        }
+        Some(global)
+    }
+
     /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
     /// stores the result on the `Root` process.
     ///
@@ -1002,6 +2077,7 @@ pub trait Root: AsCommunicator {
         O: Operation,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             ffi::MPI_Reduce(
                 sendbuf.pointer(),
@@ -1034,6 +2110,7 @@ pub trait Root: AsCommunicator {
         O: Operation,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             ffi::MPI_Reduce(
                 sendbuf.pointer(),
@@ -1047,6 +2124,126 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Performs a global reduction under the operation `op` of `count` instances of `datatype` in
+    /// `sendbuf` and stores the result on the `Root` process.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Safety
+    /// See `CommunicatorCollectives::all_reduce_into_with_datatype()`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    unsafe fn reduce_into_with_datatype<S: ?Sized, D, O>(
+        &self,
+        sendbuf: &S,
+        count: Count,
+        datatype: &D,
+        op: O,
+    ) where
+        S: Pointer,
+        D: Datatype,
+        O: Operation,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        check_operation(&op, datatype.as_raw());
+        ffi::MPI_Reduce(
+            sendbuf.pointer(),
+            ptr::null_mut(),
+            count,
+            datatype.as_raw(),
+            op.as_raw(),
+            self.root_rank(),
+            self.as_communicator().as_raw(),
+        );
+    }
+
+    /// Performs a global reduction under the operation `op` of `count` instances of `datatype` in
+    /// `sendbuf` and stores the result in `recvbuf` on the `Root` process.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Safety
+    /// See `CommunicatorCollectives::all_reduce_into_with_datatype()`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    unsafe fn reduce_into_root_with_datatype<S: ?Sized, R: ?Sized, D, O>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+        count: Count,
+        datatype: &D,
+        op: O,
+    ) where
+        S: Pointer,
+        R: PointerMut,
+        D: Datatype,
+        O: Operation,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        check_operation(&op, datatype.as_raw());
+        ffi::MPI_Reduce(
+            sendbuf.pointer(),
+            recvbuf.pointer_mut(),
+            count,
+            datatype.as_raw(),
+            op.as_raw(),
+            self.root_rank(),
+            self.as_communicator().as_raw(),
+        );
+    }
+
+    /// Performs a global reduction under the operation `op` of a single value from every process
+    /// and returns the result on the `Root` process.
+    ///
+    /// Returns `Some(result)` on the `Root` and `None` on all other processes. This function must
+    /// be called on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    fn reduce<T, O>(&self, value: &T, op: O) -> Option<T>
+    where
+        T: Equivalence,
+        O: Operation,
+    {
+        if self.as_communicator().rank() != self.root_rank() {
+            self.reduce_into(value, op);
+            return None;
+        }
+        check_operation(&op, T::equivalent_datatype().as_raw());
+        let result = unsafe {
+            with_uninitialized(|result| {
+                ffi::MPI_Reduce(
+                    value.pointer(),
+                    result as _,
+                    1,
+                    T::equivalent_datatype().as_raw(),
+                    op.as_raw(),
+                    self.root_rank(),
+                    self.as_communicator().as_raw(),
+                )
+            })
+            .1
+        };
+        Some(result)
+    }
+
     /// Initiate broadcast of a value from the `Root` process to all other processes.
     ///
     /// # Examples
@@ -1453,6 +2650,7 @@ pub trait Root: AsCommunicator {
         Sc: Scope<'a>,
     {
         assert_ne!(self.as_communicator().rank(), self.root_rank());
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -1499,6 +2697,7 @@ pub trait Root: AsCommunicator {
         Sc: Scope<'a>,
     {
         assert_eq!(self.as_communicator().rank(), self.root_rank());
+        check_operation(&op, sendbuf.as_datatype().as_raw());
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -1518,6 +2717,107 @@ pub trait Root: AsCommunicator {
             )
         }
     }
+
+    /// Spawns `maxprocs` processes running the program described by `command`.
+    ///
+    /// Only the program and the arguments of `command` are used and they are only significant on
+    /// the `Root` process. This function must be called on all processes in the `Communicator` of
+    /// the `Root`. The spawned processes form the remote group of the returned
+    /// `InterCommunicator`. They can obtain their end of it via `Universe::parent()`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/spawn.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 10.3.2
+    fn spawn(&self, command: &Command, maxprocs: Rank) -> InterCommunicator {
+        let program = c_string(command.get_program());
+        let args: Vec<CString> = command.get_args().map(c_string).collect();
+        let mut argv: Vec<*mut c_char> = args
+            .iter()
+            .map(|arg| arg.as_ptr() as *mut c_char)
+            .chain(iter::once(ptr::null_mut()))
+            .collect();
+        unsafe {
+            InterCommunicator::from_raw_unchecked(
+                with_uninitialized(|intercomm| {
+                    ffi::MPI_Comm_spawn(
+                        program.as_ptr(),
+                        argv.as_mut_ptr(),
+                        maxprocs,
+                        ffi::RSMPI_INFO_NULL,
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        intercomm,
+                        ffi::RSMPI_ERRCODES_IGNORE,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Spawns processes running different programs.
+    ///
+    /// Like `spawn()` but `maxprocs[i]` processes run the program described by `commands[i]`.
+    /// All spawned processes share the remote group of the returned `InterCommunicator`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 10.3.3
+    fn spawn_multiple(&self, commands: &[Command], maxprocs: &[Rank]) -> InterCommunicator {
+        assert_eq!(
+            commands.len(),
+            maxprocs.len(),
+            "'commands' and 'maxprocs' must be the same length"
+        );
+
+        let programs: Vec<CString> = commands
+            .iter()
+            .map(|command| c_string(command.get_program()))
+            .collect();
+        let args: Vec<Vec<CString>> = commands
+            .iter()
+            .map(|command| command.get_args().map(c_string).collect())
+            .collect();
+        let mut program_ptrs: Vec<*mut c_char> = programs
+            .iter()
+            .map(|program| program.as_ptr() as *mut c_char)
+            .collect();
+        let mut argvs: Vec<Vec<*mut c_char>> = args
+            .iter()
+            .map(|args| {
+                args.iter()
+                    .map(|arg| arg.as_ptr() as *mut c_char)
+                    .chain(iter::once(ptr::null_mut()))
+                    .collect()
+            })
+            .collect();
+        let mut argv_ptrs: Vec<*mut *mut c_char> =
+            argvs.iter_mut().map(|argv| argv.as_mut_ptr()).collect();
+        let infos = vec![unsafe { ffi::RSMPI_INFO_NULL }; commands.len()];
+
+        unsafe {
+            InterCommunicator::from_raw_unchecked(
+                with_uninitialized(|intercomm| {
+                    ffi::MPI_Comm_spawn_multiple(
+                        maxprocs.count(),
+                        program_ptrs.as_mut_ptr(),
+                        argv_ptrs.as_mut_ptr(),
+                        maxprocs.as_ptr(),
+                        infos.as_ptr(),
+                        self.root_rank(),
+                        self.as_communicator().as_raw(),
+                        intercomm,
+                        ffi::RSMPI_ERRCODES_IGNORE,
+                    )
+                })
+                .1,
+            )
+        }
+    }
 }
 
 impl<'a, C: 'a + Communicator> Root for Process<'a, C> {
@@ -1526,6 +2826,208 @@ impl<'a, C: 'a + Communicator> Root for Process<'a, C> {
     }
 }
 
+/// Converts the length of a slice of `T`s into the count type taken by `MPI_Allreduce_c()` and
+/// friends, rejecting zero-sized `T`s like `Collection::count()`
+#[cfg(feature = "mpi4")]
+fn large_count<T>(len: usize) -> ffi::MPI_Count {
+    assert_not_zero_sized::<T>();
+    len.value_as()
+        .expect("Length of slice cannot be expressed as an MPI_Count.")
+}
+
+/// Converts the length of a slice of `T`s into the count type taken by `MPI_Allreduce_c()` and
+/// friends, rejecting zero-sized `T`s like `Collection::count()`
+#[cfg(not(feature = "mpi4"))]
+fn large_count<T>(len: usize) -> Count {
+    assert_not_zero_sized::<T>();
+    len.value_as().expect(
+        "Length of slice cannot be expressed as an MPI Count, enable the `mpi4` feature to use \
+         the large count functions of MPI-4.",
+    )
+}
+
+/// Whether the `single-process-shortcuts` feature is enabled and `comm` is an intra-communicator
+/// that contains only the calling process
+fn is_self_only<C: ?Sized + Communicator>(comm: &C) -> bool {
+    cfg!(feature = "single-process-shortcuts")
+        && comm.size() == 1
+        && unsafe {
+            with_uninitialized(|flag| ffi::MPI_Comm_test_inter(comm.as_raw(), flag)).1 == 0
+        }
+}
+
+/// Panics on all processes of `comm` if they do not agree on the values of `arguments` passed to
+/// the collective `function`.
+fn check_consistent_arguments(comm: MPI_Comm, function: &str, arguments: &[(&str, i64)]) {
+    let values: Vec<i64> = arguments.iter().map(|&(_, value)| value).collect();
+    let mut min = vec![0i64; values.len()];
+    let mut max = vec![0i64; values.len()];
+    unsafe {
+        for (result, op) in [(&mut min, ffi::RSMPI_MIN), (&mut max, ffi::RSMPI_MAX)] {
+            ffi::MPI_Allreduce(
+                values.as_ptr() as *const _,
+                result.as_mut_ptr() as *mut _,
+                values[..].count(),
+                i64::equivalent_datatype().as_raw(),
+                op,
+                comm,
+            );
+        }
+    }
+    for (i, &(name, value)) in arguments.iter().enumerate() {
+        assert_eq!(
+            min[i], max[i],
+            "Processes disagree on the {} passed to {}: this process passed {}, the values range \
+             from {} to {}.",
+            name, function, value, min[i], max[i]
+        );
+    }
+}
+
+/// The size and extent in bytes of `datatype`
+fn datatype_size_and_extent(datatype: MPI_Datatype) -> (i64, i64) {
+    unsafe {
+        let (_, size) = with_uninitialized(|size| ffi::MPI_Type_size(datatype, size));
+        let (_, _, extent) =
+            with_uninitialized2(|lb, extent| ffi::MPI_Type_get_extent(datatype, lb, extent));
+        (i64::from(size), extent.value_as().unwrap())
+    }
+}
+
+/// Identifies `op` independently of the process, handles of predefined operations may differ
+/// between processes. All user-defined operations share the same identifier.
+fn operation_id(op: MPI_Op) -> i64 {
+    let predefined = unsafe {
+        [
+            ffi::RSMPI_MAX,
+            ffi::RSMPI_MIN,
+            ffi::RSMPI_SUM,
+            ffi::RSMPI_PROD,
+            ffi::RSMPI_LAND,
+            ffi::RSMPI_BAND,
+            ffi::RSMPI_LOR,
+            ffi::RSMPI_BOR,
+            ffi::RSMPI_LXOR,
+            ffi::RSMPI_BXOR,
+            ffi::RSMPI_MINLOC,
+            ffi::RSMPI_MAXLOC,
+        ]
+    };
+    predefined
+        .iter()
+        .position(|&predefined| predefined == op)
+        .map_or(-1, |i| i as i64)
+}
+
+/// Panics in debug builds if `op` is a built-in operation that is not defined for `datatype`, e.g.
+/// a bitwise operation on floating point numbers.
+///
+/// Only the datatypes equivalent to `bool`, floating point and complex numbers are checked against
+/// the classes documented for `SystemOperation`. Derived datatypes and user-defined operations are
+/// left to MPI.
+fn check_operation<O: ?Sized + Operation>(op: &O, datatype: MPI_Datatype) {
+    if cfg!(debug_assertions) {
+        let op = op.as_raw();
+        let (boolean, floating, complex) = unsafe {
+            (
+                datatype == ffi::RSMPI_C_BOOL,
+                datatype == ffi::RSMPI_FLOAT || datatype == ffi::RSMPI_DOUBLE,
+                datatype == ffi::RSMPI_C_FLOAT_COMPLEX || datatype == ffi::RSMPI_C_DOUBLE_COMPLEX,
+            )
+        };
+        let defined = unsafe {
+            if op == ffi::RSMPI_MAX || op == ffi::RSMPI_MIN {
+                !boolean && !complex
+            } else if op == ffi::RSMPI_SUM || op == ffi::RSMPI_PROD {
+                !boolean
+            } else if op == ffi::RSMPI_LAND || op == ffi::RSMPI_LOR || op == ffi::RSMPI_LXOR {
+                !floating && !complex
+            } else if op == ffi::RSMPI_BAND || op == ffi::RSMPI_BOR || op == ffi::RSMPI_BXOR {
+                !boolean && !floating && !complex
+            } else {
+                true
+            }
+        };
+        assert!(
+            defined,
+            "The built-in operation is not defined for the datatype of the buffer, see \
+             `SystemOperation` for the classes of datatypes each operation supports."
+        );
+    }
+}
+
+/// Copies the contents of `sendbuf` into `recvbuf` if both are described by the same count of the
+/// same predefined datatype without padding. Returns `false` without copying anything otherwise.
+///
+/// Derived datatypes may have holes or overlapping entries, so they are never copied bytewise.
+fn copy_predefined<S: ?Sized, R: ?Sized>(sendbuf: &S, recvbuf: &mut R) -> bool
+where
+    S: Buffer,
+    R: BufferMut,
+{
+    let datatype = sendbuf.as_datatype().as_raw();
+    if datatype != recvbuf.as_datatype().as_raw() || sendbuf.count() != recvbuf.count() {
+        return false;
+    }
+    unsafe {
+        let (mut num_integers, mut num_addresses, mut num_datatypes, mut combiner) = (0, 0, 0, 0);
+        ffi::MPI_Type_get_envelope(
+            datatype,
+            &mut num_integers,
+            &mut num_addresses,
+            &mut num_datatypes,
+            &mut combiner,
+        );
+        if combiner != ffi::RSMPI_COMBINER_NAMED {
+            return false;
+        }
+        let size: usize = with_uninitialized(|size| ffi::MPI_Type_size(datatype, size))
+            .1
+            .value_as()
+            .unwrap();
+        let (_, _, extent) =
+            with_uninitialized2(|lb, extent| ffi::MPI_Type_get_extent(datatype, lb, extent));
+        if extent.value_as::<usize>().ok() != Some(size) {
+            return false;
+        }
+        let bytes = size * sendbuf.count().value_as::<usize>().unwrap();
+        ptr::copy_nonoverlapping(
+            sendbuf.pointer() as *const u8,
+            recvbuf.pointer_mut() as *mut u8,
+            bytes,
+        );
+    }
+    true
+}
+
+/// Splits `len` elements into `size` chunks whose lengths differ by at most one
+fn balanced_counts(len: Count, size: Rank) -> Vec<Count> {
+    (0..size)
+        .map(|rank| len / size + if rank < len % size { 1 } else { 0 })
+        .collect()
+}
+
+/// Displacements of consecutive chunks of lengths `counts`
+fn displacements(counts: &[Count]) -> Vec<Count> {
+    counts
+        .iter()
+        .scan(0, |acc, &count| {
+            let displ = *acc;
+            *acc += count;
+            Some(displ)
+        })
+        .collect()
+}
+
+/// Converts a program name or argument to a C string for use in `spawn()`
+fn c_string(s: &OsStr) -> CString {
+    CString::new(
+        s.to_str()
+            .expect("Program names and arguments must be valid UTF-8."),
+    )
+    .expect("Program names and arguments must not contain nul bytes.")
+}
+
 /// An operation to be used in a reduction or scan type operation, e.g. `MPI_SUM`
 pub trait Operation: AsRaw<Raw = MPI_Op> {
     /// Returns whether the operation is commutative.
@@ -1545,6 +3047,18 @@ impl<'a, T: 'a + Operation> Operation for &'a T {}
 
 /// A built-in operation like `MPI_SUM`
 ///
+/// The built-in operations are only defined for certain classes of datatypes. Using them with
+/// other datatypes, e.g. a bitwise operation on floating point numbers, is erroneous.
+///
+/// - **ordering** (`max`, `min`): integer and floating point types
+/// - **arithmetic** (`sum`, `product`): integer, floating point and complex types
+/// - **logical** (`logical_and`, `logical_or`, `logical_xor`): integer types and `bool`
+/// - **bitwise** (`bitwise_and`, `bitwise_or`, `bitwise_xor`): integer types and bytes
+///
+/// In debug builds, the reductions panic if a built-in operation is used with the datatype
+/// equivalent to `bool`, a floating point or a complex type outside of these classes.
+/// `all_reduce_checked()` leaves the check to MPI and reports its error instead.
+///
 /// # Examples
 ///
 /// See `examples/reduce.rs`
@@ -1556,9 +3070,9 @@ impl<'a, T: 'a + Operation> Operation for &'a T {}
 pub struct SystemOperation(MPI_Op);
 
 macro_rules! system_operation_constructors {
-    ($($ctor:ident => $val:path),*) => (
-        $(pub fn $ctor() -> SystemOperation {
-            //! A built-in operation
+    ($($(#[$attr:meta])* $ctor:ident => $val:path),*) => (
+        $($(#[$attr])*
+        pub fn $ctor() -> SystemOperation {
             SystemOperation(unsafe { $val })
         })*
     )
@@ -1566,16 +3080,30 @@ macro_rules! system_operation_constructors {
 
 impl SystemOperation {
     system_operation_constructors! {
+        /// `MPI_MAX`, the maximum, for integer and floating point types
         max => ffi::RSMPI_MAX,
+        /// `MPI_MIN`, the minimum, for integer and floating point types
         min => ffi::RSMPI_MIN,
+        /// `MPI_SUM`, the sum, for integer, floating point and complex types
         sum => ffi::RSMPI_SUM,
+        /// `MPI_PROD`, the product, for integer, floating point and complex types
         product => ffi::RSMPI_PROD,
+        /// `MPI_LAND`, logical and, for integer types and `bool`
         logical_and => ffi::RSMPI_LAND,
+        /// `MPI_BAND`, bitwise and, for integer types and bytes
         bitwise_and => ffi::RSMPI_BAND,
+        /// `MPI_LOR`, logical or, for integer types and `bool`
         logical_or => ffi::RSMPI_LOR,
+        /// `MPI_BOR`, bitwise or, for integer types and bytes
         bitwise_or => ffi::RSMPI_BOR,
+        /// `MPI_LXOR`, logical exclusive or, for integer types and `bool`
         logical_xor => ffi::RSMPI_LXOR,
-        bitwise_xor => ffi::RSMPI_BXOR
+        /// `MPI_BXOR`, bitwise exclusive or, for integer types and bytes
+        bitwise_xor => ffi::RSMPI_BXOR,
+        /// `MPI_MINLOC`, the minimum and the smallest index it occurs at, for `ValueIndex`
+        min_loc => ffi::RSMPI_MINLOC,
+        /// `MPI_MAXLOC`, the maximum and the smallest index it occurs at, for `ValueIndex`
+        max_loc => ffi::RSMPI_MAXLOC
     }
 }
 
@@ -1588,6 +3116,97 @@ unsafe impl AsRaw for SystemOperation {
 
 impl Operation for SystemOperation {}
 
+/// An error of a collective operation that all processes agree on
+///
+/// Returned by `CommunicatorCollectives::all_reduce_checked()`. Carries the error of the process
+/// with the lowest rank that failed, so that all processes see the same error regardless of
+/// whether the operation failed locally.
+///
+/// # Examples
+///
+/// See `examples/error_handler.rs`
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CollectiveError {
+    rank: Rank,
+    error: Error,
+}
+
+impl CollectiveError {
+    /// The rank of the process whose error is reported
+    pub fn rank(&self) -> Rank {
+        self.rank
+    }
+
+    /// The error that occurred on the process with rank `rank()`
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for CollectiveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "collective operation failed on rank {}: {}",
+            self.rank, self.error
+        )
+    }
+}
+
+impl error::Error for CollectiveError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A value paired with an index, e.g. the rank that contributed it
+///
+/// Reducing `ValueIndex`es with `SystemOperation::min_loc()` or `SystemOperation::max_loc()`
+/// yields the extremal `value` along with the `index` it is paired with. If the extremal value
+/// occurs more than once, the smallest of the indices is returned.
+///
+/// `ValueIndex` is equivalent to the MPI pair datatypes `MPI_FLOAT_INT`, `MPI_DOUBLE_INT`,
+/// `MPI_SHORT_INT` and `MPI_2INT`.
+///
+/// # Examples
+///
+/// See `examples/reduce.rs`
+///
+/// # Standard section(s)
+///
+/// 5.9.4
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ValueIndex<T> {
+    /// The value being compared
+    pub value: T,
+    /// The index paired with the value
+    pub index: c_int,
+}
+
+impl<T> ValueIndex<T> {
+    /// Pairs `value` with `index`.
+    pub fn new(value: T, index: c_int) -> Self {
+        ValueIndex { value, index }
+    }
+}
+
+macro_rules! equivalent_value_index_datatype {
+    ($rstype:ty, $mpitype:path) => {
+        unsafe impl Equivalence for ValueIndex<$rstype> {
+            type Out = SystemDatatype;
+            fn equivalent_datatype() -> Self::Out {
+                unsafe { DatatypeRef::from_raw($mpitype) }
+            }
+        }
+    };
+}
+
+equivalent_value_index_datatype!(f32, ffi::RSMPI_FLOAT_INT);
+equivalent_value_index_datatype!(f64, ffi::RSMPI_DOUBLE_INT);
+equivalent_value_index_datatype!(i16, ffi::RSMPI_SHORT_INT);
+equivalent_value_index_datatype!(i32, ffi::RSMPI_2INT);
+
 trait Erased {}
 
 impl<T> Erased for T {}
@@ -1868,6 +3487,25 @@ impl UnsafeUserOperation {
     }
 }
 
+/// Derive the seed of the random stream of process `rank` from a common base `seed`.
+///
+/// Mixes `seed` and `rank` with the SplitMix64 finalizer, so that the seeds of different ranks
+/// are well separated even for small, consecutive base seeds and ranks. The result is the same for
+/// the same inputs on every platform. Use it with the seed returned by
+/// `CommunicatorCollectives::broadcast_seed()` to seed a per-process random number generator.
+///
+/// # Examples
+///
+/// See `examples/broadcast_seed.rs`
+pub fn seed_for_rank(seed: u64, rank: Rank) -> u64 {
+    let mut z = seed
+        .wrapping_add(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add((rank as u64).wrapping_mul(0xbf58_476d_1ce4_e5b9));
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
 /// Perform a local reduction.
 ///
 /// # Examples
@@ -1884,6 +3522,7 @@ where
     R: BufferMut,
     O: Operation,
 {
+    check_operation(&op, inbuf.as_datatype().as_raw());
     unsafe {
         ffi::MPI_Reduce_local(
             inbuf.pointer(),
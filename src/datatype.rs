@@ -5,8 +5,9 @@
 //!
 //! An MPI datatype describes a memory layout and semantics (e.g. in a collective reduce
 //! operation). There are several pre-defined `SystemDatatype`s which directly correspond to Rust
-//! primitive types, such as `MPI_DOUBLE` and `f64`. A direct relationship between a Rust type and
-//! an MPI datatype is covered by the `Equivalence` trait. Starting from the
+//! primitive types, such as `MPI_DOUBLE` and `f64`. Datatypes corresponding to Fortran intrinsic
+//! types, such as `MPI_INTEGER`, are available from the `fortran` module. A direct relationship
+//! between a Rust type and an MPI datatype is covered by the `Equivalence` trait. Starting from the
 //! `SystemDatatype`s, the user can build various `UserDatatype`s, e.g. to describe the layout of a
 //! struct (which should then implement `Equivalence`) or to intrusively describe parts of
 //! an object in memory like all elements below the diagonal of a dense matrix stored in row-major
@@ -44,7 +45,10 @@
 //! A `Buffer` describes a specific piece of data in memory that MPI should operate on. In addition
 //! to specifying the datatype of the data. It knows the address in memory where the data begins
 //! and how many instances of the datatype are contained in the data. The `Buffer` trait is
-//! implemented for slices that contain types implementing `Equivalence`.
+//! implemented for slices that contain types implementing `Equivalence`. With the `ndarray`
+//! feature enabled, it is also implemented for `ndarray` arrays and views in standard layout.
+//! With the `num-complex` feature enabled, `Complex<f32>` and `Complex<f64>` are equivalent to
+//! the C complex datatypes of MPI, so that predefined operations like `sum()` work on them.
 //!
 //! In order to use arbitrary datatypes to describe the contents of a slice, the `View` type is
 //! provided. However, since it can be used to instruct the underlying MPI implementation to
@@ -52,23 +56,18 @@
 //!
 //! # Unfinished features
 //!
-//! - **4.1.3**: Subarray datatype constructors, `MPI_Type_create_subarray()`,
 //! - **4.1.4**: Distributed array datatype constructors, `MPI_Type_create_darray()`
 //! - **4.1.5**: Address and size functions, `MPI_Get_address()`, `MPI_Aint_add()`,
 //! `MPI_Aint_diff()`, `MPI_Type_size()`, `MPI_Type_size_x()`
-//! - **4.1.7**: Extent and bounds of datatypes: `MPI_Type_get_extent()`,
-//! `MPI_Type_get_extent_x()`, `MPI_Type_create_resized()`
-//! - **4.1.8**: True extent of datatypes, `MPI_Type_get_true_extent()`,
-//! `MPI_Type_get_true_extent_x()`
-//! - **4.1.11**: `MPI_Get_elements()`, `MPI_Get_elements_x()`
-//! - **4.1.13**: Decoding a datatype, `MPI_Type_get_envelope()`, `MPI_Type_get_contents()`
+//! - **4.1.7**: Extent and bounds of datatypes: `MPI_Type_get_extent_x()`
+//! - **4.1.8**: True extent of datatypes, `MPI_Type_get_true_extent_x()`
 //! - **4.3**: Canonical pack and unpack, `MPI_Pack_external()`, `MPI_Unpack_external()`,
 //! `MPI_Pack_external_size()`
 
 use std::borrow::Borrow;
 use std::marker::PhantomData;
-use std::os::raw::c_void;
-use std::{mem, slice};
+use std::os::raw::{c_int, c_void};
+use std::{fmt, mem, slice};
 
 use conv::ConvUtil;
 
@@ -79,7 +78,14 @@ use crate::ffi::MPI_Datatype;
 
 use crate::raw::traits::*;
 
-use crate::with_uninitialized;
+use crate::{get_object_name, object_name_to_c, with_uninitialized, with_uninitialized2};
+
+pub mod fortran;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+mod pack;
+
+pub use self::pack::{Packer, Unpacker};
 
 /// Datatype traits
 pub mod traits {
@@ -167,6 +173,26 @@ pub type SystemDatatype = DatatypeRef<'static>;
 
 /// A direct equivalence exists between the implementing type and an MPI datatype
 ///
+/// # Derived implementations
+///
+/// For structs without generic parameters, `#[derive(Equivalence)]` constructs and commits the
+/// datatype on the first call to `equivalent_datatype()` and caches it for the remainder of the
+/// program. Subsequent calls return a `DatatypeRef<'static>` to the same handle, so it is cheap to
+/// reuse it as a building block of other datatypes, e.g. `UserDatatype::contiguous()`. The cached
+/// datatype is owned by rsmpi and never freed, it must only be used while MPI is initialized.
+///
+/// Generic structs cannot be cached in a `static` and return a freshly committed `UserDatatype`
+/// instead, which is freed when it is dropped.
+///
+/// Enums need a primitive integer `#[repr]`, e.g. `#[repr(u8)]`. Enums without fields are
+/// equivalent to their `#[repr]` type. Enums with fields, whose fields must all be `Equivalence`,
+/// are described as a tagged union: the tag as its `#[repr]` type followed by the payload of the
+/// largest variant as bytes, so a received value has the same variant as the sent one. Use
+/// `Optional<T>` to send the equivalent of an `Option<T>`.
+///
+/// # Examples
+/// See `examples/struct.rs`
+///
 /// # Standard section(s)
 ///
 /// 3.2.2
@@ -193,6 +219,11 @@ equivalent_system_datatype!(bool, ffi::RSMPI_C_BOOL);
 equivalent_system_datatype!(f32, ffi::RSMPI_FLOAT);
 equivalent_system_datatype!(f64, ffi::RSMPI_DOUBLE);
 
+#[cfg(feature = "num-complex")]
+equivalent_system_datatype!(num_complex::Complex<f32>, ffi::RSMPI_C_FLOAT_COMPLEX);
+#[cfg(feature = "num-complex")]
+equivalent_system_datatype!(num_complex::Complex<f64>, ffi::RSMPI_C_DOUBLE_COMPLEX);
+
 equivalent_system_datatype!(i8, ffi::RSMPI_INT8_T);
 equivalent_system_datatype!(i16, ffi::RSMPI_INT16_T);
 equivalent_system_datatype!(i32, ffi::RSMPI_INT32_T);
@@ -213,6 +244,185 @@ equivalent_system_datatype!(usize, ffi::RSMPI_UINT64_T);
 #[cfg(target_pointer_width = "64")]
 equivalent_system_datatype!(isize, ffi::RSMPI_INT64_T);
 
+/// Arrays are equivalent to a contiguous datatype of `N` elements of the datatype equivalent to `T`
+///
+/// # Examples
+/// See `examples/array.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2
+unsafe impl<T, const N: usize> Equivalence for [T; N]
+where
+    T: Equivalence,
+{
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        UserDatatype::contiguous(
+            N.value_as()
+                .expect("Length of array cannot be expressed as an MPI Count."),
+            &T::equivalent_datatype(),
+        )
+    }
+}
+
+/// An optional value with a memory layout that MPI can describe
+///
+/// The memory layout of `Option<T>` is unspecified, so it cannot implement `Equivalence`.
+/// `Optional<T>` stores the same information as a `bool` flag followed by a `T` and is equivalent
+/// to the corresponding structured datatype, which allows sending optional values directly.
+/// Convert from and to `Option<T>` via `From` and `into_option()`. The payload of an empty
+/// `Optional` is zeroed and never read.
+///
+/// # Examples
+/// See `examples/optional.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2
+#[repr(C)]
+pub struct Optional<T> {
+    present: bool,
+    value: mem::MaybeUninit<T>,
+}
+
+impl<T> Optional<T> {
+    /// An `Optional` without a value
+    pub fn none() -> Self {
+        Optional {
+            present: false,
+            value: mem::MaybeUninit::zeroed(),
+        }
+    }
+
+    /// An `Optional` holding `value`
+    pub fn some(value: T) -> Self {
+        Optional {
+            present: true,
+            value: mem::MaybeUninit::new(value),
+        }
+    }
+
+    /// Whether a value is present
+    pub fn is_some(&self) -> bool {
+        self.present
+    }
+
+    /// A reference to the value, if present
+    pub fn as_option(&self) -> Option<&T> {
+        if self.present {
+            Some(unsafe { &*self.value.as_ptr() })
+        } else {
+            None
+        }
+    }
+
+    /// Converts into an `Option`, moving out the value if present
+    pub fn into_option(self) -> Option<T> {
+        let this = mem::ManuallyDrop::new(self);
+        if this.present {
+            Some(unsafe { this.value.as_ptr().read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Optional<T> {
+    fn from(option: Option<T>) -> Self {
+        option.map_or_else(Optional::none, Optional::some)
+    }
+}
+
+impl<T> From<Optional<T>> for Option<T> {
+    fn from(optional: Optional<T>) -> Self {
+        optional.into_option()
+    }
+}
+
+impl<T> Default for Optional<T> {
+    fn default() -> Self {
+        Optional::none()
+    }
+}
+
+impl<T: Clone> Clone for Optional<T> {
+    fn clone(&self) -> Self {
+        self.as_option().cloned().into()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Optional<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_option() == other.as_option()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Optional<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Optional").field(&self.as_option()).finish()
+    }
+}
+
+impl<T> Drop for Optional<T> {
+    fn drop(&mut self) {
+        if self.present {
+            unsafe { self.value.as_mut_ptr().drop_in_place() }
+        }
+    }
+}
+
+/// `Optional<T>` is equivalent to a structured datatype of a `bool` and the datatype equivalent
+/// to `T`, padded to the size of `Optional<T>`
+unsafe impl<T> Equivalence for Optional<T>
+where
+    T: Equivalence,
+{
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        // With `#[repr(C)]`, the value follows the one byte flag at the next multiple of its
+        // alignment.
+        let offset = mem::align_of::<T>();
+        let datatype = UncommittedUserDatatype::structured(
+            &[1, 1],
+            &[0, offset.value_as().unwrap()],
+            &[
+                UncommittedDatatypeRef::from(&bool::equivalent_datatype()),
+                UncommittedDatatypeRef::from(&T::equivalent_datatype()),
+            ],
+        );
+        UserDatatype::resized(
+            &datatype,
+            0,
+            mem::size_of::<Self>()
+                .value_as()
+                .expect("Size of Optional cannot be expressed as an MPI Address."),
+        )
+    }
+}
+
+/// Describes the storage order of multi-dimensional arrays
+///
+/// # Standard section(s)
+///
+/// 4.1.3
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Order {
+    /// Row-major order, the last dimension varies fastest
+    C,
+    /// Column-major order, the first dimension varies fastest
+    Fortran,
+}
+
+impl Order {
+    fn as_raw(self) -> c_int {
+        match self {
+            Order::C => unsafe { ffi::RSMPI_ORDER_C },
+            Order::Fortran => unsafe { ffi::RSMPI_ORDER_FORTRAN },
+        }
+    }
+}
+
 /// A user defined MPI datatype
 ///
 /// # Standard section(s)
@@ -254,6 +464,9 @@ impl UserDatatype {
 
     /// Like `vector()` but `stride` is given in bytes rather than elements of `oldtype`.
     ///
+    /// # Examples
+    /// See `examples/heterogeneous.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 4.1.2
@@ -287,6 +500,9 @@ impl UserDatatype {
     /// Block `i` will be `blocklengths[i]` items of datytpe `oldtype` long and displaced by
     /// `dispplacements[i]` bytes.
     ///
+    /// # Examples
+    /// See `examples/heterogeneous.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 4.1.2
@@ -355,6 +571,42 @@ impl UserDatatype {
         UncommittedUserDatatype::structured(blocklengths, displacements, types).commit()
     }
 
+    /// Constructs a new datatype describing an n-dimensional subarray of an n-dimensional array.
+    /// Dimension `i` of the full array has `sizes[i]` elements of type `oldtype`, of which
+    /// `subsizes[i]` elements starting at `starts[i]` are part of the subarray.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::subarray(sizes, subsizes, starts, order, oldtype).commit()
+    }
+
+    /// Constructs a new datatype that is identical to `oldtype` except for its lower bound, which
+    /// is set to `lb`, and its extent, which is set to `extent`. Both are given in bytes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn resized<D>(oldtype: &D, lb: Address, extent: Address) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::resized(oldtype, lb, extent).commit()
+    }
+
     /// Creates a DatatypeRef from this datatype object.
     pub fn as_ref(&self) -> DatatypeRef<'_> {
         unsafe { DatatypeRef::from_raw(self.as_raw()) }
@@ -411,12 +663,6 @@ impl<'a> From<&'a UserDatatype> for DatatypeRef<'a> {
     }
 }
 
-impl<'a> From<&'a UserDatatype> for UncommittedDatatypeRef<'a> {
-    fn from(datatype: &'a UserDatatype) -> Self {
-        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
-    }
-}
-
 /// Represents an MPI datatype that has not yet been committed. Can be used to build up more complex
 /// datatypes before committing.
 ///
@@ -475,6 +721,9 @@ impl UncommittedUserDatatype {
 
     /// Like `vector()` but `stride` is given in bytes rather than elements of `oldtype`.
     ///
+    /// # Examples
+    /// See `examples/heterogeneous.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 4.1.2
@@ -540,6 +789,9 @@ impl UncommittedUserDatatype {
     /// Block `i` will be `blocklengths[i]` items of datytpe `oldtype` long and displaced by
     /// `dispplacements[i]` bytes.
     ///
+    /// # Examples
+    /// See `examples/heterogeneous.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 4.1.2
@@ -666,6 +918,75 @@ impl UncommittedUserDatatype {
         }
     }
 
+    /// Constructs a new datatype describing an n-dimensional subarray of an n-dimensional array.
+    /// Dimension `i` of the full array has `sizes[i]` elements of type `oldtype`, of which
+    /// `subsizes[i]` elements starting at `starts[i]` are part of the subarray.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            sizes.len(),
+            subsizes.len(),
+            "'sizes', 'subsizes', and 'starts' must be the same length"
+        );
+        assert_eq!(
+            sizes.len(),
+            starts.len(),
+            "'sizes', 'subsizes', and 'starts' must be the same length"
+        );
+
+        unsafe {
+            UncommittedUserDatatype(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_subarray(
+                        sizes.count(),
+                        sizes.as_ptr(),
+                        subsizes.as_ptr(),
+                        starts.as_ptr(),
+                        order.as_raw(),
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Constructs a new datatype that is identical to `oldtype` except for its lower bound, which
+    /// is set to `lb`, and its extent, which is set to `extent`. Both are given in bytes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn resized<D>(oldtype: &D, lb: Address, extent: Address) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_resized(oldtype.as_raw(), lb, extent, newtype)
+                })
+                .1,
+            )
+        }
+    }
+
     /// Commits a datatype to a specific representation so that it can be used in MPI calls.
     ///
     /// # Standard section(s)
@@ -720,12 +1041,105 @@ impl UncommittedDatatype for UncommittedUserDatatype {
     type DuplicatedDatatype = UncommittedUserDatatype;
 }
 
-impl<'a> From<&'a UncommittedUserDatatype> for UncommittedDatatypeRef<'a> {
-    fn from(datatype: &'a UncommittedUserDatatype) -> Self {
+impl<'a, D> From<&'a D> for UncommittedDatatypeRef<'a>
+where
+    D: UncommittedDatatype,
+{
+    fn from(datatype: &'a D) -> Self {
         unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
     }
 }
 
+/// Builds a structured datatype out of fields whose layout is only known at run time
+///
+/// Each field is described by a number of elements, a displacement in bytes from the start of the
+/// struct and the datatype of its elements. This is the run time counterpart to
+/// `#[derive(Equivalence)]` and uses the same `MPI_Type_create_struct()` primitive.
+///
+/// # Examples
+/// See `examples/struct_builder.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2
+#[derive(Default)]
+pub struct StructDatatypeBuilder<'a> {
+    blocklengths: Vec<Count>,
+    displacements: Vec<Address>,
+    types: Vec<UncommittedDatatypeRef<'a>>,
+}
+
+impl<'a> StructDatatypeBuilder<'a> {
+    /// Creates a builder for a struct without any fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a field consisting of `count` elements of type `datatype` that starts
+    /// `displacement` bytes from the start of the struct.
+    ///
+    /// # Panics
+    ///
+    /// Fields have to be added in order, i.e. `displacement` must not be smaller than that of the
+    /// previously added field.
+    pub fn field<D>(&mut self, count: Count, displacement: Address, datatype: &'a D) -> &mut Self
+    where
+        D: UncommittedDatatype,
+    {
+        if let Some(&previous) = self.displacements.last() {
+            assert!(
+                previous <= displacement,
+                "Field displacements must be non-decreasing, got {} after {}.",
+                displacement,
+                previous
+            );
+        }
+        self.blocklengths.push(count);
+        self.displacements.push(displacement);
+        self.types.push(datatype.into());
+        self
+    }
+
+    /// The number of fields added so far
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Whether no fields have been added yet
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Constructs and commits the structured datatype.
+    ///
+    /// The extent of the resulting datatype can be queried via `get_extent()`. If it does not
+    /// match the stride of consecutive structs in memory, e.g. because of trailing padding, use
+    /// `build_with_extent()` instead.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn build(&self) -> UserDatatype {
+        UncommittedUserDatatype::structured(&self.blocklengths, &self.displacements, &self.types)
+            .commit()
+    }
+
+    /// Constructs and commits the structured datatype with its lower bound set to `0` and its
+    /// extent set to `extent`, typically the size of the struct in bytes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn build_with_extent(&self, extent: Address) -> UserDatatype {
+        let datatype = UncommittedUserDatatype::structured(
+            &self.blocklengths,
+            &self.displacements,
+            &self.types,
+        );
+        UserDatatype::resized(&datatype, 0, extent)
+    }
+}
+
 /// A Datatype describes the layout of messages in memory.
 ///
 /// `Datatype` always represents a committed datatype that can be immediately used for sending and
@@ -743,6 +1157,14 @@ pub trait UncommittedDatatype: AsRaw<Raw = MPI_Datatype> {
 
     /// Creates a new datatype with the same key-values as this datatype.
     ///
+    /// The duplicate is a new handle that is owned by the caller and freed independently of this
+    /// datatype, e.g. a library can duplicate a datatype passed in by the caller (such as a
+    /// `DatatypeRef` returned by a derived `Equivalence`) and keep its copy for as long as it
+    /// needs to. Duplicates of committed datatypes are committed as well.
+    ///
+    /// # Examples
+    /// See `examples/datatype_dup.rs`
+    ///
     /// # Standard section(s)
     /// 4.1.10
     fn dup(&self) -> Self::DuplicatedDatatype {
@@ -752,6 +1174,158 @@ pub trait UncommittedDatatype: AsRaw<Raw = MPI_Datatype> {
             )
         }
     }
+
+    /// Returns the lower bound and the extent of the datatype in bytes.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.7
+    fn get_extent(&self) -> (Address, Address) {
+        unsafe {
+            let (_, lb, extent) = with_uninitialized2(|lb, extent| {
+                ffi::MPI_Type_get_extent(self.as_raw(), lb, extent)
+            });
+            (lb, extent)
+        }
+    }
+
+    /// Returns the true lower bound and the true extent of the datatype in bytes, i.e. the bounds
+    /// of the data described by the datatype, ignoring any artificial bounds set via `resized()`.
+    ///
+    /// # Standard section(s)
+    /// 4.1.8
+    fn get_true_extent(&self) -> (Address, Address) {
+        unsafe {
+            let (_, lb, extent) = with_uninitialized2(|lb, extent| {
+                ffi::MPI_Type_get_true_extent(self.as_raw(), lb, extent)
+            });
+            (lb, extent)
+        }
+    }
+
+    /// Sets the name of the datatype.
+    ///
+    /// The name is shown by debuggers and profiling tools. It is truncated to fit into
+    /// `MPI_MAX_OBJECT_NAME` bytes.
+    ///
+    /// # Examples
+    /// See `examples/datatype_name.rs`
+    ///
+    /// # Standard section(s)
+    /// 6.8
+    fn set_name(&self, name: &str) {
+        let c_name = object_name_to_c(name);
+        unsafe {
+            ffi::MPI_Type_set_name(self.as_raw(), c_name.as_ptr());
+        }
+    }
+
+    /// Returns the name of the datatype.
+    ///
+    /// Predefined datatypes are named after their C identifier, e.g. `MPI_INT`, other datatypes
+    /// have an empty name unless one has been set.
+    ///
+    /// # Examples
+    /// See `examples/datatype_name.rs`
+    ///
+    /// # Standard section(s)
+    /// 6.8
+    fn get_name(&self) -> String {
+        unsafe {
+            get_object_name(|name, resultlen| {
+                ffi::MPI_Type_get_name(self.as_raw(), name, resultlen)
+            })
+        }
+    }
+
+    /// Returns the constructor that was used to create the datatype and the number of its
+    /// arguments.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn envelope(&self) -> Envelope {
+        unsafe {
+            let mut envelope = Envelope {
+                num_integers: 0,
+                num_addresses: 0,
+                num_datatypes: 0,
+                combiner: Combiner::Named,
+            };
+            let mut combiner = 0;
+            ffi::MPI_Type_get_envelope(
+                self.as_raw(),
+                &mut envelope.num_integers,
+                &mut envelope.num_addresses,
+                &mut envelope.num_datatypes,
+                &mut combiner,
+            );
+            envelope.combiner = Combiner::from(combiner);
+            envelope
+        }
+    }
+
+    /// Returns the arguments of the constructor that was used to create the datatype.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datatype is a predefined datatype, which has not been constructed from
+    /// arguments.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn contents(&self) -> Contents {
+        let envelope = self.envelope();
+        assert_ne!(
+            envelope.combiner,
+            Combiner::Named,
+            "Predefined datatypes do not have contents."
+        );
+        let num_integers = envelope.num_integers.value_as().unwrap();
+        let num_addresses = envelope.num_addresses.value_as().unwrap();
+        let num_datatypes = envelope.num_datatypes.value_as().unwrap();
+        let mut integers: Vec<c_int> = Vec::with_capacity(num_integers);
+        let mut addresses: Vec<Address> = Vec::with_capacity(num_addresses);
+        let mut datatypes: Vec<MPI_Datatype> = Vec::with_capacity(num_datatypes);
+        unsafe {
+            ffi::MPI_Type_get_contents(
+                self.as_raw(),
+                envelope.num_integers,
+                envelope.num_addresses,
+                envelope.num_datatypes,
+                integers.as_mut_ptr(),
+                addresses.as_mut_ptr(),
+                datatypes.as_mut_ptr(),
+            );
+            // MPI_Type_get_contents has filled exactly as many elements as the envelope announced.
+            integers.set_len(num_integers);
+            addresses.set_len(num_addresses);
+            datatypes.set_len(num_datatypes);
+        }
+        let datatypes = datatypes
+            .into_iter()
+            .map(|datatype| unsafe {
+                let borrowed = UncommittedDatatypeRef::from_raw(datatype);
+                if borrowed.envelope().combiner == Combiner::Named {
+                    DecodedDatatype::Named(borrowed)
+                } else {
+                    DecodedDatatype::Derived(UncommittedUserDatatype::from_raw(datatype))
+                }
+            })
+            .collect();
+        Contents {
+            integers,
+            addresses,
+            datatypes,
+        }
+    }
 }
 impl<'a, D> UncommittedDatatype for &'a D
 where
@@ -760,6 +1334,126 @@ where
     type DuplicatedDatatype = <D as UncommittedDatatype>::DuplicatedDatatype;
 }
 
+/// The constructor that was used to create a datatype
+///
+/// # Standard section(s)
+///
+/// 4.1.13
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Combiner {
+    /// A predefined datatype, e.g. `MPI_DOUBLE`
+    Named,
+    /// `dup()`
+    Dup,
+    /// `contiguous()`
+    Contiguous,
+    /// `vector()`
+    Vector,
+    /// `heterogeneous_vector()`
+    HeterogeneousVector,
+    /// `indexed()`
+    Indexed,
+    /// `heterogeneous_indexed()`
+    HeterogeneousIndexed,
+    /// `indexed_block()`
+    IndexedBlock,
+    /// `heterogeneous_indexed_block()`
+    HeterogeneousIndexedBlock,
+    /// `structured()`
+    Structured,
+    /// `subarray()`
+    Subarray,
+    /// `MPI_Type_create_darray()`
+    Darray,
+    /// `resized()`
+    Resized,
+    /// Any other constructor, e.g. one of the Fortran 90 parameterized types
+    Other(c_int),
+}
+
+impl From<c_int> for Combiner {
+    fn from(combiner: c_int) -> Combiner {
+        unsafe {
+            match combiner {
+                c if c == ffi::RSMPI_COMBINER_NAMED => Combiner::Named,
+                c if c == ffi::RSMPI_COMBINER_DUP => Combiner::Dup,
+                c if c == ffi::RSMPI_COMBINER_CONTIGUOUS => Combiner::Contiguous,
+                c if c == ffi::RSMPI_COMBINER_VECTOR => Combiner::Vector,
+                c if c == ffi::RSMPI_COMBINER_HVECTOR => Combiner::HeterogeneousVector,
+                c if c == ffi::RSMPI_COMBINER_INDEXED => Combiner::Indexed,
+                c if c == ffi::RSMPI_COMBINER_HINDEXED => Combiner::HeterogeneousIndexed,
+                c if c == ffi::RSMPI_COMBINER_INDEXED_BLOCK => Combiner::IndexedBlock,
+                c if c == ffi::RSMPI_COMBINER_HINDEXED_BLOCK => Combiner::HeterogeneousIndexedBlock,
+                c if c == ffi::RSMPI_COMBINER_STRUCT => Combiner::Structured,
+                c if c == ffi::RSMPI_COMBINER_SUBARRAY => Combiner::Subarray,
+                c if c == ffi::RSMPI_COMBINER_DARRAY => Combiner::Darray,
+                c if c == ffi::RSMPI_COMBINER_RESIZED => Combiner::Resized,
+                c => Combiner::Other(c),
+            }
+        }
+    }
+}
+
+/// The constructor of a datatype and the number of its arguments
+///
+/// # Standard section(s)
+///
+/// 4.1.13
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Envelope {
+    /// The number of integer arguments, e.g. counts and block lengths
+    pub num_integers: Count,
+    /// The number of address arguments, e.g. byte displacements
+    pub num_addresses: Count,
+    /// The number of datatype arguments
+    pub num_datatypes: Count,
+    /// The constructor used to create the datatype
+    pub combiner: Combiner,
+}
+
+/// The arguments of the constructor of a datatype
+///
+/// The meaning and order of the arguments depend on the `Combiner`, see the table in section
+/// 4.1.13 of the MPI standard. E.g. for `Combiner::Vector`, `integers` contains the count, the
+/// block length and the stride and `datatypes` contains the old type.
+///
+/// # Standard section(s)
+///
+/// 4.1.13
+pub struct Contents {
+    /// The integer arguments
+    pub integers: Vec<c_int>,
+    /// The address arguments
+    pub addresses: Vec<Address>,
+    /// The datatype arguments
+    pub datatypes: Vec<DecodedDatatype>,
+}
+
+/// A datatype argument returned by `UncommittedDatatype::contents()`
+///
+/// Predefined datatypes are borrowed, while derived datatypes are new handles owned by the caller
+/// that are freed when they are dropped. Their commit state is unspecified.
+pub enum DecodedDatatype {
+    /// A predefined datatype, e.g. `MPI_DOUBLE`
+    Named(UncommittedDatatypeRef<'static>),
+    /// A derived datatype
+    Derived(UncommittedUserDatatype),
+}
+
+unsafe impl AsRaw for DecodedDatatype {
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        match self {
+            DecodedDatatype::Named(datatype) => datatype.as_raw(),
+            DecodedDatatype::Derived(datatype) => datatype.as_raw(),
+        }
+    }
+}
+
+impl UncommittedDatatype for DecodedDatatype {
+    type DuplicatedDatatype = UncommittedUserDatatype;
+}
+
 /// Something that has an associated datatype
 pub unsafe trait AsDatatype {
     /// The type of the associated MPI datatype (e.g. `SystemDatatype` or `UserDatatype`)
@@ -841,14 +1535,58 @@ pub mod internal {
             }
         }
     }
+
+    #[cfg(feature = "derive")]
+    pub fn check_derive_equivalence_extent(
+        type_name: &str,
+        datatype: &super::UserDatatype,
+        size: usize,
+    ) {
+        use super::UncommittedDatatype;
+        use conv::ConvUtil;
+
+        let (_, extent) = datatype.get_extent();
+        if extent.value_as::<usize>() != Some(size) {
+            panic!(
+                "\n\
+                 RSMPI PANIC: Datatype extent does not match type size\n\
+                 \n\
+                 The datatype generated by #[derive(Equivalence)] for `{}` has an extent of {} \
+                 bytes, but the type occupies {} bytes in memory. Consecutive values of this type \
+                 would not be laid out the way MPI expects. This usually means that the type has \
+                 padding or an alignment that MPI cannot infer from its fields, e.g. due to \
+                 `#[repr(align(N))]`.\n",
+                type_name, extent, size
+            );
+        }
+    }
 }
 
 /// A countable collection of things.
 pub unsafe trait Collection {
     /// How many things are in this collection.
+    ///
+    /// # Panics
+    ///
+    /// Implementations for slices and arrays panic if the number of elements exceeds the range of
+    /// `Count` rather than truncating it, or if the elements are of a zero-sized type.
     fn count(&self) -> Count;
 }
 
+/// Panics if `T` is a zero-sized type.
+///
+/// The number of elements in a buffer or message of a zero-sized type cannot be inferred from its
+/// size in bytes, so slices, arrays and `Vec`s of zero-sized types are rejected wherever their
+/// count is computed. Single values of zero-sized types are fine.
+pub(crate) fn assert_not_zero_sized<T>() {
+    assert!(
+        mem::size_of::<T>() != 0,
+        "Buffers of the zero-sized type `{}` are not supported, the number of elements is not \
+         defined for zero-sized types.",
+        std::any::type_name::<T>()
+    );
+}
+
 unsafe impl<T> Collection for T
 where
     T: Equivalence,
@@ -863,6 +1601,7 @@ where
     T: Equivalence,
 {
     fn count(&self) -> Count {
+        assert_not_zero_sized::<T>();
         self.len()
             .value_as()
             .expect("Length of slice cannot be expressed as an MPI Count.")
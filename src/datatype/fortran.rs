@@ -0,0 +1,86 @@
+//! Datatypes corresponding to Fortran intrinsic types
+//!
+//! These are useful when exchanging messages with Fortran code in the same MPI job, e.g. a
+//! coupled simulation where one component is written in Rust and the other in Fortran. Messages
+//! described with these datatypes match messages sent or received by Fortran peers using the same
+//! datatype.
+//!
+//! The sizes of Fortran intrinsic types depend on the Fortran compiler that MPI was built with and
+//! on its flags (e.g. `-fdefault-integer-8`). Typically `INTEGER`, `REAL` and `LOGICAL` occupy 4
+//! bytes and `DOUBLE PRECISION` occupies 8 bytes, but this is not guaranteed. Check the extent of
+//! the datatype via `UncommittedDatatype::get_extent()` before pairing it with a Rust buffer.
+//!
+//! If MPI was built without Fortran support, the handles of these datatypes may be
+//! `MPI_DATATYPE_NULL`, which must not be used for communication or queried for its extent. The
+//! functions below return `None` in that case.
+//!
+//! None of these datatypes implement `Equivalence` for any Rust type. Use them with the explicit
+//! datatype variants of the point to point operations, e.g. `Destination::send_with_datatype()`.
+//!
+//! # Examples
+//! See `examples/fortran_datatypes.rs`
+//!
+//! # Standard section(s)
+//!
+//! 3.2.2
+
+use crate::ffi;
+use crate::raw::traits::*;
+
+use super::{DatatypeRef, SystemDatatype};
+
+macro_rules! fortran_datatype {
+    ($(#[$attr:meta])* $name:ident, $mpitype:path) => {
+        $(#[$attr])*
+        ///
+        /// # Standard section(s)
+        ///
+        /// 3.2.2
+        pub fn $name() -> Option<SystemDatatype> {
+            let handle = unsafe { $mpitype };
+            if handle == unsafe { ffi::RSMPI_DATATYPE_NULL } {
+                None
+            } else {
+                Some(unsafe { DatatypeRef::from_raw(handle) })
+            }
+        }
+    };
+}
+
+fortran_datatype!(
+    /// `MPI_INTEGER`, a Fortran `INTEGER`, typically 4 bytes
+    integer,
+    ffi::RSMPI_INTEGER
+);
+fortran_datatype!(
+    /// `MPI_REAL`, a Fortran `REAL`, typically 4 bytes
+    real,
+    ffi::RSMPI_REAL
+);
+fortran_datatype!(
+    /// `MPI_DOUBLE_PRECISION`, a Fortran `DOUBLE PRECISION`, typically 8 bytes
+    double_precision,
+    ffi::RSMPI_DOUBLE_PRECISION
+);
+fortran_datatype!(
+    /// `MPI_COMPLEX`, a Fortran `COMPLEX`, a pair of `REAL`s
+    complex,
+    ffi::RSMPI_COMPLEX
+);
+fortran_datatype!(
+    /// `MPI_DOUBLE_COMPLEX`, a Fortran `DOUBLE COMPLEX`, a pair of `DOUBLE PRECISION`s
+    double_complex,
+    ffi::RSMPI_DOUBLE_COMPLEX
+);
+fortran_datatype!(
+    /// `MPI_LOGICAL`, a Fortran `LOGICAL`, typically 4 bytes
+    ///
+    /// The representation of `.TRUE.` is compiler specific, do not assume it is `1`.
+    logical,
+    ffi::RSMPI_LOGICAL
+);
+fortran_datatype!(
+    /// `MPI_CHARACTER`, a single Fortran `CHARACTER`, 1 byte
+    character,
+    ffi::RSMPI_CHARACTER
+);
@@ -0,0 +1,95 @@
+//! Buffer implementations for `ndarray` arrays
+//!
+//! Arrays and array views in standard (row-major, contiguous) layout can be used directly as
+//! buffers. The buffer consists of all elements of the array in logical order. Using an array
+//! that is not in standard layout, e.g. a strided slice or a transposed view, as a buffer panics.
+
+use std::os::raw::c_void;
+
+use conv::ConvUtil;
+use ndarray::{ArrayBase, Data, DataMut, Dimension};
+
+use super::{
+    assert_not_zero_sized, AsDatatype, Buffer, BufferMut, Collection, Equivalence, Pointer,
+    PointerMut,
+};
+use crate::Count;
+
+fn assert_standard_layout<S, D>(array: &ArrayBase<S, D>)
+where
+    S: Data,
+    D: Dimension,
+{
+    assert!(
+        array.is_standard_layout(),
+        "Only arrays in standard layout can be used as MPI buffers, use `as_standard_layout()` \
+         to obtain a contiguous copy."
+    );
+}
+
+unsafe impl<A, S, D> AsDatatype for ArrayBase<S, D>
+where
+    A: Equivalence,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    type Out = <A as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <A as Equivalence>::equivalent_datatype()
+    }
+}
+
+unsafe impl<A, S, D> Collection for ArrayBase<S, D>
+where
+    A: Equivalence,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn count(&self) -> Count {
+        assert_standard_layout(self);
+        assert_not_zero_sized::<A>();
+        self.len()
+            .value_as()
+            .expect("Length of array cannot be expressed as an MPI Count.")
+    }
+}
+
+unsafe impl<A, S, D> Pointer for ArrayBase<S, D>
+where
+    A: Equivalence,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn pointer(&self) -> *const c_void {
+        assert_standard_layout(self);
+        self.as_ptr() as _
+    }
+}
+
+unsafe impl<A, S, D> PointerMut for ArrayBase<S, D>
+where
+    A: Equivalence,
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    fn pointer_mut(&mut self) -> *mut c_void {
+        assert_standard_layout(self);
+        self.as_mut_ptr() as _
+    }
+}
+
+unsafe impl<A, S, D> Buffer for ArrayBase<S, D>
+where
+    A: Equivalence,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+}
+
+unsafe impl<A, S, D> BufferMut for ArrayBase<S, D>
+where
+    A: Equivalence,
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+}
@@ -0,0 +1,208 @@
+//! Packing several buffers into one message
+//!
+//! `Communicator::pack_into()` and `Communicator::unpack_into()` require threading the position
+//! in the packed bytes through every call. `Packer` and `Unpacker` keep track of the position, so
+//! that mixed messages, e.g. a header struct followed by a variable-length array, can be packed
+//! into a single message with one call per part and unpacked again in the same order.
+
+use std::mem::MaybeUninit;
+
+use conv::ConvUtil;
+
+use super::{Buffer, BufferMut, Collection, Equivalence};
+use crate::ffi;
+use crate::raw::traits::*;
+use crate::topology::Communicator;
+use crate::Count;
+
+/// Packs a sequence of buffers into one contiguous byte message
+///
+/// Every call to `pack()` appends the packed contents of a buffer, growing the underlying byte
+/// vector as needed. `finish()` returns the packed bytes, which can be sent with any datatype
+/// agnostic operation and unpacked by an `Unpacker` on a communicator of the same processes.
+///
+/// # Examples
+/// See `examples/pack_into.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2
+pub struct Packer<'c, C: ?Sized> {
+    comm: &'c C,
+    buffer: Vec<u8>,
+    position: Count,
+}
+
+impl<'c, C> Packer<'c, C>
+where
+    C: ?Sized + Communicator,
+{
+    /// Starts a new, empty message for communication on `comm`.
+    pub fn new(comm: &'c C) -> Self {
+        Packer {
+            comm,
+            buffer: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Appends the contents of `inbuf` to the message.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2, see MPI_Pack
+    pub fn pack<Buf>(&mut self, inbuf: &Buf) -> &mut Self
+    where
+        Buf: ?Sized + Buffer,
+    {
+        let required = self.len() + self.comm.packed_size(inbuf);
+        self.buffer.resize(required, 0);
+        self.position = self
+            .comm
+            .pack_into(inbuf, &mut self.buffer[..], self.position);
+        self
+    }
+
+    /// Appends the length of `slice` followed by its elements to the message.
+    ///
+    /// The counterpart of `Unpacker::unpack_vec()`, which reads the length to know how many
+    /// elements follow.
+    pub fn pack_slice<T>(&mut self, slice: &[T]) -> &mut Self
+    where
+        T: Equivalence,
+    {
+        self.pack(&slice.count()).pack(slice)
+    }
+
+    /// The number of bytes packed so far
+    pub fn len(&self) -> usize {
+        self.position.value_as().unwrap()
+    }
+
+    /// Whether nothing has been packed yet
+    pub fn is_empty(&self) -> bool {
+        self.position == 0
+    }
+
+    /// Finishes the message and returns the packed bytes.
+    pub fn finish(mut self) -> Vec<u8> {
+        let len = self.len();
+        self.buffer.truncate(len);
+        self.buffer
+    }
+}
+
+/// Unpacks the buffers of a message produced by a `Packer`
+///
+/// The parts of the message have to be unpacked in the order they were packed in and with the
+/// same datatypes.
+///
+/// # Examples
+/// See `examples/pack_into.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2
+pub struct Unpacker<'c, 'b, C: ?Sized> {
+    comm: &'c C,
+    buffer: &'b [u8],
+    position: Count,
+}
+
+impl<'c, 'b, C> Unpacker<'c, 'b, C>
+where
+    C: ?Sized + Communicator,
+{
+    /// Starts unpacking the message in `buffer` that was packed for communication on `comm`.
+    pub fn new(comm: &'c C, buffer: &'b [u8]) -> Self {
+        Unpacker {
+            comm,
+            buffer,
+            position: 0,
+        }
+    }
+
+    /// Unpacks the next part of the message into `outbuf`.
+    ///
+    /// # Safety
+    ///
+    /// The next part of the message must have been packed from a buffer with the same type map
+    /// as `outbuf`, otherwise `outbuf` may end up holding invalid values.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2, see MPI_Unpack
+    pub unsafe fn unpack_into<Buf>(&mut self, outbuf: &mut Buf) -> &mut Self
+    where
+        Buf: ?Sized + BufferMut,
+    {
+        self.position = self.comm.unpack_into(self.buffer, outbuf, self.position);
+        self
+    }
+
+    /// Unpacks the next part of the message as a single value.
+    ///
+    /// # Safety
+    ///
+    /// The next part of the message must have been packed from a `T`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2, see MPI_Unpack
+    pub unsafe fn unpack<T>(&mut self) -> T
+    where
+        T: Equivalence,
+    {
+        let mut value = MaybeUninit::<T>::uninit();
+        ffi::MPI_Unpack(
+            self.buffer.as_ptr() as *const _,
+            self.buffer.count(),
+            &mut self.position,
+            value.as_mut_ptr() as _,
+            1,
+            T::equivalent_datatype().as_raw(),
+            self.comm.as_raw(),
+        );
+        // MPI_Unpack has written one instance of `T` to `value`.
+        value.assume_init()
+    }
+
+    /// Unpacks the next part of the message as a length followed by that many elements, as packed
+    /// by `Packer::pack_slice()`.
+    ///
+    /// # Safety
+    ///
+    /// The next part of the message must have been packed by `Packer::pack_slice()` from a slice
+    /// of `T`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2, see MPI_Unpack
+    pub unsafe fn unpack_vec<T>(&mut self) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        let count: Count = self.unpack();
+        let len = count
+            .value_as()
+            .expect("Packed slice has a negative length.");
+        let mut values: Vec<T> = Vec::with_capacity(len);
+        ffi::MPI_Unpack(
+            self.buffer.as_ptr() as *const _,
+            self.buffer.count(),
+            &mut self.position,
+            values.as_mut_ptr() as _,
+            count,
+            T::equivalent_datatype().as_raw(),
+            self.comm.as_raw(),
+        );
+        // MPI_Unpack has written `count` instances of `T` to `values`.
+        values.set_len(len);
+        values
+    }
+
+    /// The number of bytes of the message that have not been unpacked yet
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.position.value_as::<usize>().unwrap()
+    }
+}
@@ -4,25 +4,30 @@
 //!
 //! # Unfinished features
 //!
-//! - **8.1.2**: `MPI_TAG_UB`, ...
+//! - **8.1.2**: `MPI_HOST`, `MPI_IO`
 //! - **8.2**: Memory allocation
-//! - **8.3, 8.4, and 8.5**: Error handling
+//! - **8.3**: User-defined error handlers, `MPI_Comm_create_errhandler()`, ...
+//! - **8.5**: User-defined error codes and classes
 
 use std::{
     cmp::Ordering,
+    error, fmt, mem,
     os::raw::{c_char, c_double, c_int, c_void},
     ptr,
     string::FromUtf8Error,
     sync::RwLock,
     thread::{self, ThreadId},
+    time::Duration,
 };
 
 use conv::ConvUtil;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::ffi;
-use crate::topology::SystemCommunicator;
-use crate::{with_uninitialized, with_uninitialized2};
+use crate::ffi::MPI_Errhandler;
+use crate::raw::traits::*;
+use crate::topology::{InterCommunicator, SystemCommunicator};
+use crate::{with_uninitialized, with_uninitialized2, Tag};
 
 /// Internal data structure used to uphold certain MPI invariants.
 /// State is currently only used with the derive feature.
@@ -37,6 +42,7 @@ pub(crate) static UNIVERSE_STATE: Lazy<RwLock<Option<UniverseState>>> =
 /// Global context
 pub struct Universe {
     buffer: Option<Vec<u8>>,
+    parent: Option<InterCommunicator>,
 }
 
 impl Universe {
@@ -50,12 +56,81 @@ impl Universe {
         SystemCommunicator::world()
     }
 
+    /// The communicator connecting this process to the processes that spawned it
+    ///
+    /// Returns `None` if this process was not started via `Root::spawn()` or
+    /// `Root::spawn_multiple()`.
+    ///
+    /// # Examples
+    /// See `examples/spawn.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 10.3.2
+    pub fn parent(&self) -> Option<&InterCommunicator> {
+        self.parent.as_ref()
+    }
+
+    /// Level of multithreading supported by this MPI universe
+    ///
+    /// See the `Threading` enum.
+    ///
+    /// # Examples
+    /// See `examples/init_with_threading.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.4.3
+    pub fn threading_support(&self) -> Threading {
+        threading_support()
+    }
+
+    /// The largest tag that can be used for point to point messages
+    ///
+    /// See `tag_upper_bound()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.1.2
+    pub fn tag_upper_bound(&self) -> Tag {
+        tag_upper_bound()
+    }
+
+    /// Keep MPI initialized beyond the lifetime of this `Universe`.
+    ///
+    /// Normally, MPI is finalized when the `Universe` is dropped. A leaked `Universe` is never
+    /// dropped, so MPI is not finalized by rsmpi at all. This is useful when rsmpi is used as part
+    /// of a larger application that also uses MPI directly, e.g. from C or Fortran code, and
+    /// finalizes MPI itself.
+    ///
+    /// The caller becomes responsible for calling `MPI_Finalize()` exactly once before the program
+    /// exits. After that, the returned reference and all other rsmpi handles must not be used
+    /// anymore.
+    ///
+    /// # Examples
+    /// See `examples/leak_universe.rs`
+    pub fn leak(self) -> &'static mut Universe {
+        Box::leak(Box::new(self))
+    }
+
     /// The size in bytes of the buffer used for buffered communication.
     pub fn buffer_size(&self) -> usize {
         self.buffer.as_ref().map_or(0, Vec::len)
     }
 
     /// Set the size in bytes of the buffer used for buffered communication.
+    ///
+    /// Any previously attached buffer is detached first, waiting for the messages in it to be
+    /// transmitted. The buffer has to be large enough to hold all messages that are pending at the
+    /// same time, each of which occupies its packed size plus `buffered_send_overhead()` bytes.
+    /// The buffer is detached when the `Universe` is dropped.
+    ///
+    /// # Examples
+    /// See `examples/buffered.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.6
     pub fn set_buffer_size(&mut self, size: usize) {
         self.detach_buffer();
 
@@ -75,6 +150,12 @@ impl Universe {
     }
 
     /// Detach the buffer used for buffered communication.
+    ///
+    /// Blocks until all messages in the buffer have been transmitted.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.6
     pub fn detach_buffer(&mut self) {
         if let Some(buffer) = self.buffer.take() {
             let mut addr: *const c_void = ptr::null();
@@ -105,7 +186,15 @@ impl Drop for Universe {
             .write()
             .expect("rsmpi internal error: UNIVERSE_STATE lock poisoned");
 
+        // Another library might have finalized MPI behind our back. Finalizing twice is an error
+        // and the handles owned by the universe cannot be freed anymore.
+        if is_finalized() {
+            mem::forget(self.parent.take());
+            return;
+        }
+
         self.detach_buffer();
+        self.parent.take();
         unsafe {
             ffi::MPI_Finalize();
         }
@@ -174,24 +263,197 @@ impl From<c_int> for Threading {
     }
 }
 
+/// Describes how errors raised by MPI functions are handled.
+///
+/// # Examples
+/// See `examples/error_handler.rs`
+///
+/// # Standard section(s)
+///
+/// 8.3
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ErrorHandler {
+    /// Errors abort all processes of the job, this is the default.
+    Fatal,
+    /// Errors are returned to the caller as error codes.
+    Return,
+}
+
+unsafe impl AsRaw for ErrorHandler {
+    type Raw = MPI_Errhandler;
+    fn as_raw(&self) -> Self::Raw {
+        match self {
+            ErrorHandler::Fatal => unsafe { ffi::RSMPI_ERRORS_ARE_FATAL },
+            ErrorHandler::Return => unsafe { ffi::RSMPI_ERRORS_RETURN },
+        }
+    }
+}
+
+/// An error returned by an MPI function
+///
+/// Errors are only returned to the caller if the `ErrorHandler::Return` error handler is set,
+/// otherwise MPI aborts the program. `Error` stores the raw error code along with its error class
+/// and description, so it can be propagated via `?` like any other error.
+///
+/// # Examples
+/// See `examples/error_handler.rs`
+///
+/// # Standard section(s)
+///
+/// 8.4
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Error {
+    code: c_int,
+    class: c_int,
+    message: String,
+}
+
+impl Error {
+    /// Describes the error that corresponds to the error code `code`.
+    ///
+    /// Queries the error class and the description from MPI, so MPI must still be initialized.
+    pub fn from_code(code: c_int) -> Error {
+        let message = error_string(code)
+            .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+        Error {
+            code,
+            class: error_class(code),
+            message,
+        }
+    }
+
+    /// The raw error code returned by the MPI function
+    pub fn code(&self) -> c_int {
+        self.code
+    }
+
+    /// The error class of the error code, e.g. `MPI_ERR_RANK`
+    pub fn class(&self) -> c_int {
+        self.class
+    }
+
+    /// The description of the error provided by the MPI library
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (MPI error code {})", self.message, self.code)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Converts the return code of an MPI function into a `Result`.
+pub(crate) fn check(code: c_int) -> Result<(), Error> {
+    if code == unsafe { ffi::RSMPI_SUCCESS } {
+        Ok(())
+    } else {
+        Err(Error::from_code(code))
+    }
+}
+
+/// The error class of the error code `code`
+///
+/// # Examples
+/// See `examples/error_handler.rs`
+///
+/// # Standard section(s)
+///
+/// 8.4
+pub fn error_class(code: c_int) -> c_int {
+    unsafe { with_uninitialized(|class| ffi::MPI_Error_class(code, class)).1 }
+}
+
+/// Describes the error that corresponds to the error code `code`.
+///
+/// Can return an `Err` if the description is not a UTF-8 string.
+///
+/// # Examples
+/// See `examples/error_handler.rs`
+///
+/// # Standard section(s)
+///
+/// 8.4
+pub fn error_string(code: c_int) -> Result<String, FromUtf8Error> {
+    let bufsize = unsafe { ffi::RSMPI_MAX_ERROR_STRING }
+        .value_as()
+        .unwrap_or_else(|_| {
+            panic!(
+                "MPI_MAX_ERROR_STRING ({}) cannot be expressed as a usize.",
+                unsafe { ffi::RSMPI_MAX_ERROR_STRING }
+            )
+        });
+    let mut buf = vec![0u8; bufsize];
+    let mut len: c_int = 0;
+
+    unsafe {
+        ffi::MPI_Error_string(code, buf.as_mut_ptr() as *mut c_char, &mut len);
+    }
+    buf.truncate(len.value_as().unwrap_or_else(|_| {
+        panic!(
+            "Length of error string ({}) cannot be expressed as a usize.",
+            len
+        )
+    }));
+    String::from_utf8(buf)
+}
+
 /// Whether the MPI library has been initialized
-pub(crate) fn is_initialized() -> bool {
+///
+/// This remains `true` after the MPI library has been finalized.
+///
+/// # Examples
+/// See `examples/init_twice.rs`
+///
+/// # Standard section(s)
+///
+/// 8.7
+pub fn is_initialized() -> bool {
     unsafe { with_uninitialized(|initialized| ffi::MPI_Initialized(initialized)).1 != 0 }
 }
 
-/// Whether the MPI library has been initialized
-/// NOTE: Used by "derive" feature
-#[allow(unused)]
-pub(crate) fn is_finalized() -> bool {
+/// Whether the MPI library has been finalized
+///
+/// # Examples
+/// See `examples/init_twice.rs`
+///
+/// # Standard section(s)
+///
+/// 8.7
+pub fn is_finalized() -> bool {
     unsafe { with_uninitialized(|finalized| ffi::MPI_Finalized(finalized)).1 != 0 }
 }
 
+/// Whether this process was started via `Root::spawn()` or `Root::spawn_multiple()`
+///
+/// Allows worker code to branch at startup without holding on to the `Universe`, use
+/// `Universe::parent()` to communicate with the spawning processes.
+///
+/// # Examples
+/// See `examples/spawn.rs`
+///
+/// # Standard section(s)
+///
+/// 10.3.2
+pub fn is_spawned() -> bool {
+    let (_, parent) = unsafe { with_uninitialized(|parent| ffi::MPI_Comm_get_parent(parent)) };
+    parent != unsafe { ffi::RSMPI_COMM_NULL }
+}
+
 /// Initialize MPI.
 ///
 /// If the MPI library has not been initialized so far, initializes and returns a representation
 /// of the MPI communication `Universe` which provides access to additional functions.
 /// Otherwise returns `None`.
 ///
+/// MPI can only be initialized once per process, so `None` is also returned after a previous
+/// `Universe` has been dropped and MPI has been finalized. `is_initialized()` and
+/// `is_finalized()` can be used to find out about the state of the MPI library beforehand, e.g.
+/// when MPI might have been initialized by another library.
+///
 /// Equivalent to: `initialize_with_threading(Threading::Single)`
 ///
 /// # Examples
@@ -211,6 +473,8 @@ pub fn initialize() -> Option<Universe> {
 /// additional functions as well as the level of multithreading actually supported by the
 /// implementation. Otherwise returns `None`.
 ///
+/// Like `initialize()` this returns `None` if MPI has already been initialized or finalized.
+///
 /// # Examples
 /// See `examples/init_with_threading.rs`
 ///
@@ -248,7 +512,17 @@ pub fn initialize_with_threading(threading: Threading) -> Option<(Universe, Thre
         main_thread: thread::current().id(),
     });
 
-    Some((Universe { buffer: None }, provided.into()))
+    let parent = unsafe {
+        InterCommunicator::from_raw(with_uninitialized(|parent| ffi::MPI_Comm_get_parent(parent)).1)
+    };
+
+    Some((
+        Universe {
+            buffer: None,
+            parent,
+        },
+        provided.into(),
+    ))
 }
 
 /// Level of multithreading supported by this MPI universe
@@ -310,12 +584,19 @@ pub fn library_version() -> Result<String, FromUtf8Error> {
 /// Names the processor that the calling process is running on.
 ///
 /// Can return an `Err` if the processor name is not a UTF-8 string.
+///
+/// # Examples
+/// See `examples/env_inq.rs`
+///
+/// # Standard section(s)
+///
+/// 8.1.1
 pub fn processor_name() -> Result<String, FromUtf8Error> {
     let bufsize = unsafe { ffi::RSMPI_MAX_PROCESSOR_NAME }
         .value_as()
         .unwrap_or_else(|_| {
             panic!(
-                "MPI_MAX_LIBRARY_SIZE ({}) \
+                "MPI_MAX_PROCESSOR_NAME ({}) \
                  cannot be expressed as a \
                  usize.",
                 unsafe { ffi::RSMPI_MAX_PROCESSOR_NAME }
@@ -337,14 +618,101 @@ pub fn processor_name() -> Result<String, FromUtf8Error> {
     String::from_utf8(buf)
 }
 
+/// The number of bytes a message sent in buffered mode occupies in the buffer in addition to its
+/// packed size
+///
+/// # Examples
+/// See `examples/buffered.rs`
+///
+/// # Standard section(s)
+///
+/// 3.6
+pub fn buffered_send_overhead() -> usize {
+    unsafe { ffi::RSMPI_BSEND_OVERHEAD }
+        .value_as()
+        .expect("MPI_BSEND_OVERHEAD is negative.")
+}
+
 /// Time in seconds since an arbitrary time in the past.
 ///
-/// The cheapest high-resolution timer available will be used.
+/// The cheapest high-resolution timer available will be used. The times returned on different
+/// processes are not comparable unless `time_is_global()` returns `true`.
+///
+/// # Examples
+/// See `examples/time.rs`
+///
+/// # Standard section(s)
+///
+/// 8.6
 pub fn time() -> c_double {
     unsafe { ffi::RSMPI_Wtime() }
 }
 
 /// Resolution of timer used in `time()` in seconds
+///
+/// # Standard section(s)
+///
+/// 8.6
 pub fn time_resolution() -> c_double {
     unsafe { ffi::RSMPI_Wtick() }
 }
+
+/// The time that has passed since `start`, an earlier result of `time()`, as a `Duration`
+///
+/// # Examples
+/// See `examples/time.rs`
+pub fn time_since(start: c_double) -> Duration {
+    Duration::from_secs_f64((time() - start).max(0.0))
+}
+
+/// The largest tag that can be used for point to point messages
+///
+/// Valid tags range from `0` to this value, inclusive. The standard guarantees an upper bound of
+/// at least `32767`, many implementations support much larger tags. The value does not change
+/// while MPI is initialized and is queried only once.
+///
+/// # Examples
+/// See `examples/tag_upper_bound.rs`
+///
+/// # Standard section(s)
+///
+/// 8.1.2
+pub fn tag_upper_bound() -> Tag {
+    static TAG_UB: OnceCell<Tag> = OnceCell::new();
+    *TAG_UB.get_or_init(|| {
+        let (_, value, flag) = unsafe {
+            with_uninitialized2(|value: *mut *const c_int, flag| {
+                ffi::MPI_Comm_get_attr(
+                    ffi::RSMPI_COMM_WORLD,
+                    ffi::RSMPI_TAG_UB,
+                    value as *mut c_void,
+                    flag,
+                )
+            })
+        };
+        assert!(flag != 0, "MPI_TAG_UB is not set on MPI_COMM_WORLD.");
+        unsafe { *value }
+    })
+}
+
+/// Whether the clocks used by `time()` are synchronized across all processes
+///
+/// # Examples
+/// See `examples/time.rs`
+///
+/// # Standard section(s)
+///
+/// 8.1.2
+pub fn time_is_global() -> bool {
+    let (_, value, flag) = unsafe {
+        with_uninitialized2(|value: *mut *const c_int, flag| {
+            ffi::MPI_Comm_get_attr(
+                ffi::RSMPI_COMM_WORLD,
+                ffi::RSMPI_WTIME_IS_GLOBAL,
+                value as *mut c_void,
+                flag,
+            )
+        })
+    };
+    flag != 0 && unsafe { *value } != 0
+}
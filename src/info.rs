@@ -0,0 +1,182 @@
+//! Passing hints to the MPI implementation
+//!
+//! Many MPI functions accept an `Info` object, an unordered set of `(key, value)` pairs of
+//! strings. These are used to pass optional, often implementation specific hints, e.g. about the
+//! expected access patterns of a file or a communicator.
+//!
+//! # Unfinished features
+//!
+//! - **9**: `MPI_Info_get_nthkey()`, `MPI_INFO_ENV`
+
+use std::ffi::CString;
+use std::iter::FromIterator;
+use std::os::raw::c_char;
+
+use conv::ConvUtil;
+
+use crate::ffi;
+use crate::ffi::MPI_Info;
+use crate::raw::traits::*;
+use crate::{with_uninitialized, with_uninitialized2};
+
+/// A set of `(key, value)` pairs used to pass hints to the MPI implementation
+///
+/// # Examples
+/// See `examples/info.rs`
+///
+/// # Standard section(s)
+///
+/// 9
+pub struct Info(MPI_Info);
+
+impl Info {
+    /// Creates a new, empty `Info` object.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9
+    pub fn new() -> Info {
+        unsafe { Info(with_uninitialized(|info| ffi::MPI_Info_create(info)).1) }
+    }
+
+    /// Adds the pair `(key, value)`, replacing the value of `key` if it is already present.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9
+    pub fn set(&mut self, key: &str, value: &str) {
+        let key = c_string(key);
+        let value = c_string(value);
+        unsafe {
+            ffi::MPI_Info_set(self.0, key.as_ptr(), value.as_ptr());
+        }
+    }
+
+    /// Retrieves the value associated with `key`.
+    ///
+    /// Returns `None` if `key` is not present or if its value is not a UTF-8 string.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9
+    pub fn get(&self, key: &str) -> Option<String> {
+        let key = c_string(key);
+        let (_, valuelen, flag) = unsafe {
+            with_uninitialized2(|valuelen, flag| {
+                ffi::MPI_Info_get_valuelen(self.0, key.as_ptr(), valuelen, flag)
+            })
+        };
+        if flag == 0 {
+            return None;
+        }
+
+        let mut buf = vec![
+            0u8;
+            valuelen
+                .value_as::<usize>()
+                .expect("MPI_Info_get_valuelen returned a negative length!")
+                + 1
+        ];
+        let (_, flag) = unsafe {
+            with_uninitialized(|flag| {
+                ffi::MPI_Info_get(
+                    self.0,
+                    key.as_ptr(),
+                    valuelen,
+                    buf.as_mut_ptr() as *mut c_char,
+                    flag,
+                )
+            })
+        };
+        if flag == 0 {
+            return None;
+        }
+        buf.truncate(
+            valuelen
+                .value_as()
+                .expect("MPI_Info_get_valuelen returned a negative length!"),
+        );
+        String::from_utf8(buf).ok()
+    }
+
+    /// Removes `key` and its associated value.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9
+    pub fn delete(&mut self, key: &str) {
+        if self.get(key).is_some() {
+            let key = c_string(key);
+            unsafe {
+                ffi::MPI_Info_delete(self.0, key.as_ptr());
+            }
+        }
+    }
+
+    /// The number of keys currently defined.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9
+    pub fn num_keys(&self) -> usize {
+        unsafe { with_uninitialized(|nkeys| ffi::MPI_Info_get_nkeys(self.0, nkeys)).1 }
+            .value_as()
+            .expect("MPI_Info_get_nkeys returned a negative number of keys!")
+    }
+}
+
+/// Converts an info key or value to a C string
+fn c_string(s: &str) -> CString {
+    CString::new(s).expect("Info keys and values must not contain nul bytes.")
+}
+
+impl Default for Info {
+    fn default() -> Self {
+        Info::new()
+    }
+}
+
+impl Clone for Info {
+    fn clone(&self) -> Self {
+        unsafe { Info(with_uninitialized(|newinfo| ffi::MPI_Info_dup(self.0, newinfo)).1) }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Info
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut info = Info::new();
+        for (key, value) in iter {
+            info.set(key.as_ref(), value.as_ref());
+        }
+        info
+    }
+}
+
+impl Drop for Info {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Info_free(&mut self.0);
+        }
+        assert_eq!(self.0, unsafe { ffi::RSMPI_INFO_NULL });
+    }
+}
+
+unsafe impl AsRaw for Info {
+    type Raw = MPI_Info;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl FromRaw for Info {
+    unsafe fn from_raw(handle: MPI_Info) -> Self {
+        assert_ne!(handle, ffi::RSMPI_INFO_NULL);
+        Info(handle)
+    }
+}
+
+unsafe impl MatchesRaw for Info {}
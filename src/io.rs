@@ -0,0 +1,663 @@
+//! Parallel file I/O
+//!
+//! Files are opened collectively by all processes of a communicator. Every process can then read
+//! and write parts of the file at explicit offsets, either independently or collectively, which
+//! allows the MPI library to merge the accesses of all processes into large, contiguous requests.
+//!
+//! In contrast to the rest of MPI, errors in file operations are returned to the caller by
+//! default, so most operations in this module return a `Result`.
+//!
+//! # Unfinished features
+//!
+//! - **13.2**: File manipulation, `MPI_File_delete()`, `MPI_File_preallocate()`,
+//! `MPI_File_get_group()`, `MPI_File_get_amode()`, `MPI_File_set_info()`, `MPI_File_get_info()`
+//! - **13.3**: File views, `MPI_File_set_view()`, `MPI_File_get_view()`
+//! - **13.4.3**: Data access with individual file pointers
+//! - **13.4.5**: Split collective data access with individual and shared file pointers
+//! - **13.5**: File interoperability
+//! - **13.6**: Consistency and semantics, `MPI_File_set_atomicity()`, `MPI_File_sync()`
+
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::os::raw::{c_int, c_void};
+use std::path::Path;
+
+use conv::ConvUtil;
+
+use crate::collective::{traits::*, SystemOperation};
+use crate::datatype::traits::*;
+use crate::environment::check;
+use crate::ffi;
+use crate::ffi::MPI_File;
+use crate::point_to_point::Status;
+use crate::raw::traits::*;
+use crate::request::{abort_on_unhandled_request, Request, Scope, StaticScope};
+use crate::topology::Communicator;
+use crate::{with_uninitialized, Error};
+
+/// A position in a file in bytes
+pub type Offset = ffi::MPI_Offset;
+
+/// A file that has been opened collectively by the processes of a communicator
+///
+/// The file is closed when the `File` is dropped, which is a collective operation as well.
+///
+/// # Examples
+/// See `examples/file_io.rs`
+///
+/// # Standard section(s)
+///
+/// 13.2
+pub struct File(MPI_File);
+
+impl File {
+    /// Opens an existing file for reading.
+    ///
+    /// This is a collective operation on `comm`, all processes have to pass the same `path`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.2.1
+    pub fn open<C: ?Sized, P>(comm: &C, path: P) -> Result<File, Error>
+    where
+        C: Communicator,
+        P: AsRef<Path>,
+    {
+        File::open_with_mode(comm, path.as_ref(), unsafe { ffi::RSMPI_MODE_RDONLY })
+    }
+
+    /// Creates a file for writing, truncating it if it already exists.
+    ///
+    /// This is a collective operation on `comm`, all processes have to pass the same `path`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.2.1, 13.2.4
+    pub fn create<C: ?Sized, P>(comm: &C, path: P) -> Result<File, Error>
+    where
+        C: Communicator,
+        P: AsRef<Path>,
+    {
+        let file = File::open_with_mode(comm, path.as_ref(), unsafe {
+            ffi::RSMPI_MODE_WRONLY | ffi::RSMPI_MODE_CREATE
+        })?;
+        file.set_size(0)?;
+        Ok(file)
+    }
+
+    fn open_with_mode<C: ?Sized>(comm: &C, path: &Path, amode: c_int) -> Result<File, Error>
+    where
+        C: Communicator,
+    {
+        let path = CString::new(
+            path.to_str()
+                .expect("File path cannot be expressed as a UTF-8 string."),
+        )
+        .expect("File path cannot be expressed as a C string.");
+        unsafe {
+            let (code, handle) = with_uninitialized(|handle| {
+                ffi::MPI_File_open(
+                    comm.as_raw(),
+                    path.as_ptr(),
+                    amode,
+                    ffi::RSMPI_INFO_NULL,
+                    handle,
+                )
+            });
+            check(code).map(|_| File(handle))
+        }
+    }
+
+    /// The current size of the file in bytes
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.2.6
+    pub fn size(&self) -> Result<Offset, Error> {
+        let (code, size) =
+            unsafe { with_uninitialized(|size| ffi::MPI_File_get_size(self.0, size)) };
+        check(code).map(|_| size)
+    }
+
+    /// Truncates or extends the file to `size` bytes.
+    ///
+    /// This is a collective operation, all processes have to pass the same `size`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.2.4
+    pub fn set_size(&self, size: Offset) -> Result<(), Error> {
+        check(unsafe { ffi::MPI_File_set_size(self.0, size) })
+    }
+
+    /// Writes the contents of `buf` to the file, starting `offset` bytes into the file.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.2
+    pub fn write_at<Buf: ?Sized>(&self, offset: Offset, buf: &Buf) -> Result<Status, Error>
+    where
+        Buf: Buffer,
+    {
+        let (code, status) = unsafe {
+            with_uninitialized(|status| {
+                ffi::MPI_File_write_at(
+                    self.0,
+                    offset,
+                    buf.pointer(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    status,
+                )
+            })
+        };
+        check(code).map(|_| Status::from_raw(status))
+    }
+
+    /// Writes the contents of `buf` to the file, starting `offset` bytes into the file.
+    ///
+    /// This is a collective operation, which allows the MPI library to combine the accesses of
+    /// all processes. Every process passes its own `offset` and `buf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.2
+    pub fn write_at_all<Buf: ?Sized>(&self, offset: Offset, buf: &Buf) -> Result<Status, Error>
+    where
+        Buf: Buffer,
+    {
+        let (code, status) = unsafe {
+            with_uninitialized(|status| {
+                ffi::MPI_File_write_at_all(
+                    self.0,
+                    offset,
+                    buf.pointer(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    status,
+                )
+            })
+        };
+        check(code).map(|_| Status::from_raw(status))
+    }
+
+    /// Reads from the file into `buf`, starting `offset` bytes into the file.
+    ///
+    /// Use `Status::count_of()` on the result to find out how much has been read, which can be
+    /// less than `buf` can hold at the end of the file.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.2
+    pub fn read_at<Buf: ?Sized>(&self, offset: Offset, buf: &mut Buf) -> Result<Status, Error>
+    where
+        Buf: BufferMut,
+    {
+        let (code, status) = unsafe {
+            with_uninitialized(|status| {
+                ffi::MPI_File_read_at(
+                    self.0,
+                    offset,
+                    buf.pointer_mut(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    status,
+                )
+            })
+        };
+        check(code).map(|_| Status::from_raw(status))
+    }
+
+    /// Reads from the file into `buf`, starting `offset` bytes into the file.
+    ///
+    /// This is a collective operation, see `write_at_all()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.2
+    pub fn read_at_all<Buf: ?Sized>(&self, offset: Offset, buf: &mut Buf) -> Result<Status, Error>
+    where
+        Buf: BufferMut,
+    {
+        let (code, status) = unsafe {
+            with_uninitialized(|status| {
+                ffi::MPI_File_read_at_all(
+                    self.0,
+                    offset,
+                    buf.pointer_mut(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    status,
+                )
+            })
+        };
+        check(code).map(|_| Status::from_raw(status))
+    }
+
+    /// Initiates writing the contents of `buf` to the file, starting `offset` bytes into the file.
+    ///
+    /// This is a non-blocking version of `write_at()`. Like the immediate point to point
+    /// operations, the returned `Request` borrows `buf` and is registered with `scope`. The file
+    /// is borrowed for the lifetime of the scope, so it cannot be closed while the operation is
+    /// in progress.
+    ///
+    /// # Examples
+    /// See `examples/file_immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.2
+    pub fn immediate_write_at<'a, Sc, Buf: ?Sized>(
+        &'a self,
+        scope: Sc,
+        offset: Offset,
+        buf: &'a Buf,
+    ) -> Result<Request<'a, Sc>, Error>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            let (code, request) = with_uninitialized(|request| {
+                ffi::MPI_File_iwrite_at(
+                    self.0,
+                    offset,
+                    buf.pointer(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    request,
+                )
+            });
+            check(code).map(|_| Request::from_raw(request, scope))
+        }
+    }
+
+    /// Initiates reading from the file into `buf`, starting `offset` bytes into the file.
+    ///
+    /// This is a non-blocking version of `read_at()`, see `immediate_write_at()`.
+    ///
+    /// # Examples
+    /// See `examples/file_immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.2
+    pub fn immediate_read_at<'a, Sc, Buf: ?Sized>(
+        &'a self,
+        scope: Sc,
+        offset: Offset,
+        buf: &'a mut Buf,
+    ) -> Result<Request<'a, Sc>, Error>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            let (code, request) = with_uninitialized(|request| {
+                ffi::MPI_File_iread_at(
+                    self.0,
+                    offset,
+                    buf.pointer_mut(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    request,
+                )
+            });
+            check(code).map(|_| Request::from_raw(request, scope))
+        }
+    }
+
+    /// Begins writing the contents of `buf` to the file, starting `offset` bytes into the file.
+    ///
+    /// This is the first half of a split collective version of `write_at_all()`. Other work can
+    /// be done until the returned `SplitCollective` is ended, which completes the operation.
+    /// `buf` and the file stay borrowed until then. Only one split collective operation can be
+    /// active on a file at a time.
+    ///
+    /// # Examples
+    /// See `examples/file_split.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.5
+    pub fn write_at_all_begin<'a, Sc, Buf: ?Sized>(
+        &'a mut self,
+        scope: Sc,
+        offset: Offset,
+        buf: &'a Buf,
+    ) -> Result<SplitCollective<'a, Sc>, Error>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        let code = unsafe {
+            ffi::MPI_File_write_at_all_begin(
+                self.0,
+                offset,
+                buf.pointer(),
+                buf.count(),
+                buf.as_datatype().as_raw(),
+            )
+        };
+        check(code)?;
+        Ok(SplitCollective::new(
+            self,
+            buf.pointer() as *mut c_void,
+            SplitKind::Write,
+            scope,
+        ))
+    }
+
+    /// Begins reading from the file into `buf`, starting `offset` bytes into the file.
+    ///
+    /// This is the first half of a split collective version of `read_at_all()`, see
+    /// `write_at_all_begin()`. The contents of `buf` are only valid after the returned
+    /// `SplitCollective` has been ended.
+    ///
+    /// # Examples
+    /// See `examples/file_split.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.5
+    pub fn read_at_all_begin<'a, Sc, Buf: ?Sized>(
+        &'a mut self,
+        scope: Sc,
+        offset: Offset,
+        buf: &'a mut Buf,
+    ) -> Result<SplitCollective<'a, Sc>, Error>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        let code = unsafe {
+            ffi::MPI_File_read_at_all_begin(
+                self.0,
+                offset,
+                buf.pointer_mut(),
+                buf.count(),
+                buf.as_datatype().as_raw(),
+            )
+        };
+        check(code)?;
+        Ok(SplitCollective::new(
+            self,
+            buf.pointer_mut(),
+            SplitKind::Read,
+            scope,
+        ))
+    }
+
+    /// Writes the contents of `buf` at the shared file pointer and advances it.
+    ///
+    /// All processes share one file pointer, which is at the start of the file after it has been
+    /// opened. Any process can append to the file this way without coordinating offsets with the
+    /// others, e.g. for logging. The order of concurrent writes is unspecified.
+    ///
+    /// # Examples
+    /// See `examples/file_shared.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.4
+    pub fn write_shared<Buf: ?Sized>(&self, buf: &Buf) -> Result<Status, Error>
+    where
+        Buf: Buffer,
+    {
+        let (code, status) = unsafe {
+            with_uninitialized(|status| {
+                ffi::MPI_File_write_shared(
+                    self.0,
+                    buf.pointer(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    status,
+                )
+            })
+        };
+        check(code).map(|_| Status::from_raw(status))
+    }
+
+    /// Writes the contents of `buf` on all processes at the shared file pointer, in rank order.
+    ///
+    /// This is a collective operation. The data of the processes is written one after the other
+    /// in the order of their ranks, starting at the shared file pointer, which is advanced past
+    /// the data of all processes.
+    ///
+    /// # Examples
+    /// See `examples/file_shared.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.4
+    pub fn write_ordered<Buf: ?Sized>(&self, buf: &Buf) -> Result<Status, Error>
+    where
+        Buf: Buffer,
+    {
+        let (code, status) = unsafe {
+            with_uninitialized(|status| {
+                ffi::MPI_File_write_ordered(
+                    self.0,
+                    buf.pointer(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    status,
+                )
+            })
+        };
+        check(code).map(|_| Status::from_raw(status))
+    }
+
+    /// The position of the shared file pointer in bytes
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.4
+    pub fn shared_position(&self) -> Result<Offset, Error> {
+        let (code, offset) = unsafe {
+            with_uninitialized(|offset| ffi::MPI_File_get_position_shared(self.0, offset))
+        };
+        check(code).map(|_| offset)
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_File_close(&mut self.0);
+        }
+        assert_eq!(self.0, unsafe { ffi::RSMPI_FILE_NULL });
+    }
+}
+
+unsafe impl AsRaw for File {
+    type Raw = MPI_File;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum SplitKind {
+    Read,
+    Write,
+}
+
+/// An active split collective file operation registered with a `Scope` of lifetime `'a`
+///
+/// Returned by `File::write_at_all_begin()` and `File::read_at_all_begin()`. The buffer and the
+/// file stay borrowed until the operation is completed via `end()`. Like a `Request`, the
+/// operation is registered with a `Scope`, which aborts the process if the `SplitCollective` is
+/// leaked instead of completed before the scope ends.
+///
+/// # Aborts
+///
+/// Aborts the process if the `SplitCollective` is dropped without calling `end()`. Ending the
+/// operation implicitly is a collective call that would deadlock if the other processes do not
+/// drop theirs as well, and it would have to discard the error.
+///
+/// # Examples
+/// See `examples/file_split.rs`
+///
+/// # Standard section(s)
+///
+/// 13.4.5
+#[must_use]
+pub struct SplitCollective<'a, Sc: Scope<'a> = StaticScope> {
+    file: MPI_File,
+    buf: *mut c_void,
+    kind: SplitKind,
+    ended: bool,
+    scope: Sc,
+    phantom: PhantomData<&'a mut File>,
+}
+
+impl<'a, Sc: Scope<'a>> SplitCollective<'a, Sc> {
+    fn new(file: &'a mut File, buf: *mut c_void, kind: SplitKind, scope: Sc) -> Self {
+        scope.register();
+        SplitCollective {
+            file: file.0,
+            buf,
+            kind,
+            ended: false,
+            scope,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Completes the operation.
+    ///
+    /// This is a collective operation, every process that began the operation has to end it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 13.4.5
+    pub fn end(mut self) -> Result<Status, Error> {
+        self.ended = true;
+        let (code, status) = unsafe {
+            let result = with_uninitialized(|status| match self.kind {
+                SplitKind::Read => ffi::MPI_File_read_at_all_end(self.file, self.buf, status),
+                SplitKind::Write => ffi::MPI_File_write_at_all_end(self.file, self.buf, status),
+            });
+            self.scope.unregister();
+            result
+        };
+        check(code).map(|_| Status::from_raw(status))
+    }
+}
+
+impl<'a, Sc: Scope<'a>> Drop for SplitCollective<'a, Sc> {
+    fn drop(&mut self) {
+        if !self.ended {
+            abort_on_unhandled_request();
+        }
+    }
+}
+
+/// The number of bytes occupied by one `T` in a file
+fn file_size_of<T: Equivalence>() -> Offset {
+    let (_, size) = unsafe {
+        with_uninitialized(|size| ffi::MPI_Type_size(T::equivalent_datatype().as_raw(), size))
+    };
+    Offset::from(size)
+}
+
+/// Writes the contents of `local` on all processes of `comm` to a file at `path`, in rank order.
+///
+/// The file is created or truncated. Its contents are the concatenation of the `local` slices of
+/// all processes, ordered by rank, so that `read_distributed()` with the same number of processes
+/// reads them back unchanged. This is a collective operation on `comm`.
+///
+/// Unlike `File::write_ordered()`, which advances the shared file pointer, this computes the
+/// offset of every process with an exclusive scan and writes at explicit offsets.
+///
+/// # Examples
+/// See `examples/file_io.rs`
+///
+/// # Standard section(s)
+///
+/// 13.4.2
+pub fn write_concatenated<C: ?Sized, P, T>(comm: &C, path: P, local: &[T]) -> Result<(), Error>
+where
+    C: Communicator,
+    P: AsRef<Path>,
+    T: Equivalence,
+{
+    let local_size = file_size_of::<T>()
+        * local
+            .len()
+            .value_as::<Offset>()
+            .expect("Length of slice cannot be expressed as an MPI_Offset.");
+    let mut offset: Offset = 0;
+    comm.exclusive_scan_into(&local_size, &mut offset, SystemOperation::sum());
+    // the result of an exclusive scan is undefined on the first process
+    if comm.rank() == 0 {
+        offset = 0;
+    }
+
+    let file = File::create(comm, path)?;
+    file.write_at_all(offset, local)?;
+    Ok(())
+}
+
+/// Reads the file at `path` and splits its contents evenly among the processes of `comm`.
+///
+/// The file is interpreted as a sequence of `T`. Every process receives a contiguous part of it,
+/// in rank order, and the lengths of the parts differ by at most one. This is a collective
+/// operation on `comm`.
+///
+/// # Panics
+///
+/// Panics if the datatype equivalent to `T` has a size of zero bytes, e.g. for zero-sized types.
+///
+/// # Examples
+/// See `examples/file_io.rs`
+///
+/// # Standard section(s)
+///
+/// 13.4.2
+pub fn read_distributed<C: ?Sized, P, T>(comm: &C, path: P) -> Result<Vec<T>, Error>
+where
+    C: Communicator,
+    P: AsRef<Path>,
+    T: Equivalence,
+{
+    let element_size = file_size_of::<T>();
+    assert!(
+        element_size > 0,
+        "Cannot split a file into elements of `{}`, its datatype occupies no bytes in a file.",
+        std::any::type_name::<T>()
+    );
+    let file = File::open(comm, path)?;
+    let len = file.size()? / element_size;
+
+    let size = Offset::from(comm.size());
+    let rank = Offset::from(comm.rank());
+    let local_len = len / size + if rank < len % size { 1 } else { 0 };
+    let start = rank * (len / size) + rank.min(len % size);
+
+    let local_len: usize = local_len
+        .value_as()
+        .expect("Length of file part cannot be expressed as a usize.");
+    let mut local: Vec<T> = Vec::with_capacity(local_len);
+    let (code, _) = unsafe {
+        with_uninitialized(|status| {
+            ffi::MPI_File_read_at_all(
+                file.as_raw(),
+                start * element_size,
+                local.as_mut_ptr() as _,
+                local_len
+                    .value_as()
+                    .expect("Length of file part cannot be expressed as an MPI Count."),
+                T::equivalent_datatype().as_raw(),
+                status,
+            )
+        })
+    };
+    check(code)?;
+    // MPI has read `local_len` elements of `T` from the file.
+    unsafe {
+        local.set_len(local_len);
+    }
+    Ok(local)
+}
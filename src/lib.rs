@@ -82,8 +82,8 @@
 //! Currently supported:
 //!
 //! - **Groups, Contexts, Communicators**:
-//!   - Group and (Intra-)Communicator management from section 6 is mostly complete.
-//!   - no Inter-Communicators
+//!   - Group and Communicator management from section 6 is mostly complete.
+//!   - Inter-Communicators
 //!   - no process topologies
 //! - **Point to point communication**:
 //!   - standard, buffered, synchronous and ready mode send in blocking and non-blocking variants
@@ -100,14 +100,15 @@
 //!   - varying counts operations
 //!   - reductions/scans
 //!   - blocking and non-blocking variants
+//! - **Process management**: spawning processes
 //! - **Datatypes**: Bridging between Rust types and MPI basic types as well as custom MPI datatypes
 //! which can act as views into buffers.
+//! - **Parallel I/O**: opening files and reading and writing at explicit offsets
 //!
 //! Not supported (yet):
 //!
-//! - Process management
 //! - One-sided communication (RMA)
-//! - MPI parallel I/O
+//! - MPI parallel I/O with file views
 //! - A million small things
 //!
 //! The sub-modules contain a more detailed description of which features are and are not
@@ -121,8 +122,9 @@
 //!
 //! [MPIspec]: http://www.mpi-forum.org/docs/docs.html
 
+use std::ffi::{CStr, CString};
 use std::mem::MaybeUninit;
-use std::os::raw::c_int;
+use std::os::raw::{c_char, c_int};
 
 /// The raw C language MPI API
 ///
@@ -138,10 +140,13 @@ pub mod ffi {
 pub mod collective;
 pub mod datatype;
 pub mod environment;
+pub mod info;
+pub mod io;
 pub mod point_to_point;
 pub mod raw;
 pub mod request;
 pub mod topology;
+pub mod window;
 
 /// Re-exports all traits.
 pub mod traits {
@@ -166,20 +171,44 @@ pub mod internal {
 
 #[doc(inline)]
 pub use crate::environment::{
-    initialize, initialize_with_threading, time, time_resolution, Threading,
+    initialize, initialize_with_threading, is_spawned, time, time_resolution, Error, Threading,
 };
 
 use crate::ffi::MPI_Aint;
 
-/// Encodes error values returned by MPI functions.
-pub type Error = c_int;
 /// Encodes number of values in multi-value messages.
+///
+/// This is an MPI `int`, so lengths of large buffers may not fit. rsmpi checks the conversion
+/// wherever it computes a `Count` from a slice length. Use `TryFrom` rather than an `as` cast to
+/// do the same in user code:
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(Ok(42), mpi::Count::try_from(42usize));
+/// assert!(mpi::Count::try_from(usize::MAX).is_err());
+/// ```
+///
+/// `Count` is an alias rather than a newtype, so that it can be passed to and from the raw
+/// functions in `ffi` without conversions.
 pub type Count = c_int;
 /// Can be used to tag messages on the sender side and match on the receiver side.
 pub type Tag = c_int;
 /// An address in memory
 pub type Address = MPI_Aint;
 
+/// The value MPI uses for ranks, counts and other integers that are not defined, `MPI_UNDEFINED`
+///
+/// E.g. the rank of a process within a group it is not a member of, or the count returned by
+/// `Status::elements()` for a message that does not consist of whole basic elements.
+///
+/// # Standard section(s)
+///
+/// 2.5.4
+pub fn undefined() -> c_int {
+    unsafe { ffi::RSMPI_UNDEFINED }
+}
+
 /// IntArray is used to translate Rust bool values to and from the int-bool types preferred by MPI
 /// without incurring allocation in the common case.
 type IntArray = smallvec::SmallVec<[c_int; 8]>;
@@ -206,3 +235,23 @@ where
         uninitialized2.assume_init(),
     )
 }
+
+/// Converts `name` into a C string for `MPI_*_set_name()`, truncated at a character boundary to
+/// fit into `MPI_MAX_OBJECT_NAME` bytes including the terminating nul.
+fn object_name_to_c(name: &str) -> CString {
+    let mut len = name.len().min(ffi::MPI_MAX_OBJECT_NAME as usize - 1);
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+    CString::new(&name[..len]).expect("Failed to convert the Rust string to a C string")
+}
+
+/// Retrieves an object name via one of the `MPI_*_get_name()` functions passed as `f`.
+unsafe fn get_object_name<F>(f: F) -> String
+where
+    F: FnOnce(*mut c_char, *mut c_int) -> c_int,
+{
+    let mut buf = [0 as c_char; ffi::MPI_MAX_OBJECT_NAME as usize];
+    let _ = with_uninitialized(|resultlen| f(buf.as_mut_ptr(), resultlen));
+    CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned()
+}
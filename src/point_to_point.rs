@@ -4,6 +4,21 @@
 //! `Destination` trait. Communication operations are implemented as default methods on those
 //! traits.
 //!
+//! # Message ordering
+//!
+//! Messages are non-overtaking: if a process sends two messages to the same destination and both
+//! match the same receive, the one sent first is received first. There is no ordering between
+//! messages from different senders or between messages that only match receives with different
+//! tags. A `TypedChannel` uses a duplicated communicator and a fixed tag to keep its traffic
+//! separate from all other messages.
+//!
+//! # Tags
+//!
+//! Tags of sent messages must lie in `0..=tag_upper_bound()`, see
+//! `environment::tag_upper_bound()`. Receive operations additionally accept `MPI_ANY_TAG`. In
+//! debug builds, the point to point operations panic when given a tag outside of this range
+//! instead of passing it on to MPI.
+//!
 //! # Unfinished features
 //!
 //! - **3.2.6**: `MPI_STATUS_IGNORE`
@@ -12,10 +27,16 @@
 //! `MPI_Rsend_init()`, `MPI_Recv_init()`, `MPI_Start()`, `MPI_Startall()`
 
 use std::alloc::{self, Layout};
+#[cfg(feature = "serde")]
+use std::error;
+use std::marker::PhantomData;
 use std::mem::{transmute, MaybeUninit};
-use std::{fmt, ptr};
+use std::string::FromUtf8Error;
+use std::{fmt, ptr, slice};
 
 use conv::ConvUtil;
+#[cfg(feature = "serde")]
+use serde_crate::{de::DeserializeOwned, Serialize};
 
 use super::{Count, Tag};
 
@@ -23,11 +44,15 @@ use crate::ffi;
 use crate::ffi::{MPI_Message, MPI_Status};
 
 use crate::datatype::traits::*;
+use crate::datatype::{assert_not_zero_sized, DatatypeRef};
+use crate::environment;
 use crate::raw::traits::*;
-use crate::request::{Request, Scope, StaticScope};
+#[cfg(feature = "async")]
+use crate::request::RequestFuture;
+use crate::request::{OwnedRequest, Request, Scope, StaticScope};
 use crate::topology::traits::*;
-use crate::topology::{AnyProcess, CommunicatorRelation, Process, Rank};
-use crate::{with_uninitialized, with_uninitialized2};
+use crate::topology::{AnyProcess, CommunicatorRelation, Process, Rank, UserCommunicator};
+use crate::{undefined, with_uninitialized, with_uninitialized2};
 
 // TODO: rein in _with_tag ugliness, use optional tags or make tag part of Source and Destination
 
@@ -36,6 +61,73 @@ pub mod traits {
     pub use super::{Destination, MatchedReceiveVec, Source};
 }
 
+/// The wildcard tag that matches messages with any tag, `MPI_ANY_TAG`
+///
+/// Receive operations without a `_with_tag` suffix use this tag.
+///
+/// # Examples
+/// See `examples/null_process.rs`
+///
+/// # Standard section(s)
+///
+/// 3.2.4
+pub fn any_tag() -> Tag {
+    unsafe { ffi::RSMPI_ANY_TAG }
+}
+
+/// An error encoding a value into or decoding it from a serialized message
+///
+/// Returned by `Destination::send_serialized()` and `Source::receive_deserialized()`. The encoding
+/// is an implementation detail, the error of the underlying serializer is available via
+/// `source()`.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct SerializationError(bincode::Error);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "serialized message could not be encoded or decoded: {}",
+            self.0
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl error::Error for SerializationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&*self.0)
+    }
+}
+
+/// Panics in debug builds if `tag` cannot be used to send a message.
+fn check_send_tag(tag: Tag) {
+    if cfg!(debug_assertions) {
+        let upper_bound = environment::tag_upper_bound();
+        assert!(
+            (0..=upper_bound).contains(&tag),
+            "Tag {} is out of range, send tags must be in 0..={} (MPI_TAG_UB).",
+            tag,
+            upper_bound
+        );
+    }
+}
+
+/// Panics in debug builds if `tag` cannot be used to receive a message.
+fn check_receive_tag(tag: Tag) {
+    if cfg!(debug_assertions) && tag != any_tag() {
+        let upper_bound = environment::tag_upper_bound();
+        assert!(
+            (0..=upper_bound).contains(&tag),
+            "Tag {} is out of range, receive tags must be in 0..={} (MPI_TAG_UB) or `MPI_ANY_TAG`.",
+            tag,
+            upper_bound
+        );
+    }
+}
+
 /// Something that can be used as the source in a point to point receive operation
 ///
 /// # Examples
@@ -64,6 +156,7 @@ pub unsafe trait Source: AsCommunicator {
     ///
     /// 3.8.1
     fn probe_with_tag(&self, tag: Tag) -> Status {
+        check_receive_tag(tag);
         unsafe {
             Status(
                 with_uninitialized(|status| {
@@ -107,6 +200,7 @@ pub unsafe trait Source: AsCommunicator {
     ///
     /// 3.8.2
     fn matched_probe_with_tag(&self, tag: Tag) -> (Message, Status) {
+        check_receive_tag(tag);
         let (_, message, status) = unsafe {
             with_uninitialized2(|message, status| {
                 ffi::MPI_Mprobe(
@@ -148,6 +242,7 @@ pub unsafe trait Source: AsCommunicator {
     where
         Msg: Equivalence,
     {
+        check_receive_tag(tag);
         unsafe {
             let (_, msg, status) = with_uninitialized2(|msg, status| {
                 ffi::MPI_Recv(
@@ -197,6 +292,13 @@ pub unsafe trait Source: AsCommunicator {
     ///
     /// Receive a message from `Source` `&self` tagged `tag` into `Buffer` `buf`.
     ///
+    /// The message may be shorter than `buf`, in which case only the beginning of `buf` is
+    /// overwritten. Use `Status::count_of()` on the returned `Status` to find out how many
+    /// elements have been received.
+    ///
+    /// # Examples
+    /// See `examples/receive_count.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 3.2.4
@@ -204,6 +306,7 @@ pub unsafe trait Source: AsCommunicator {
     where
         Buf: BufferMut,
     {
+        check_receive_tag(tag);
         unsafe {
             Status(
                 with_uninitialized(|status| {
@@ -226,6 +329,13 @@ pub unsafe trait Source: AsCommunicator {
     ///
     /// Receive a message from `Source` `&self` into `Buffer` `buf`.
     ///
+    /// The message may be shorter than `buf`, in which case only the beginning of `buf` is
+    /// overwritten. Use `Status::count_of()` on the returned `Status` to find out how many
+    /// elements have been received.
+    ///
+    /// # Examples
+    /// See `examples/receive_count.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 3.2.4
@@ -236,10 +346,207 @@ pub unsafe trait Source: AsCommunicator {
         self.receive_into_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
     }
 
+    /// Receive a message into a `Buffer`, but don't bother retrieving the `Status` information.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` into `Buffer` `buf`. Since no
+    /// `Status` is returned, this is only useful if the length and origin of the message are
+    /// known in advance, e.g. for fixed size messages in a tight loop.
+    ///
+    /// # Examples
+    /// See `examples/send_receive.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_with_tag_without_status<Buf: ?Sized>(&self, buf: &mut Buf, tag: Tag)
+    where
+        Buf: BufferMut,
+    {
+        check_receive_tag(tag);
+        unsafe {
+            ffi::MPI_Recv(
+                buf.pointer_mut(),
+                buf.count(),
+                buf.as_datatype().as_raw(),
+                self.source_rank(),
+                tag,
+                self.as_communicator().as_raw(),
+                ffi::RSMPI_STATUS_IGNORE,
+            );
+        }
+    }
+
+    /// Receive a message into a `Buffer`, but don't bother retrieving the `Status` information.
+    ///
+    /// Receive a message from `Source` `&self` into `Buffer` `buf`, see
+    /// `receive_into_with_tag_without_status()`.
+    ///
+    /// # Examples
+    /// See `examples/send_receive.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_without_status<Buf: ?Sized>(&self, buf: &mut Buf)
+    where
+        Buf: BufferMut,
+    {
+        self.receive_into_with_tag_without_status(buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Receive a message into a `Pointer` with an explicit datatype.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` containing at most `count` instances
+    /// of `datatype` into the memory at `buf`. Unlike `receive_into_with_tag()`, the datatype is
+    /// not derived from the element type of `buf`.
+    ///
+    /// # Examples
+    /// See `examples/explicit_datatype.rs`
+    ///
+    /// # Safety
+    /// - `count` instances of `datatype` must map `buf` without exceeding the bounds of the
+    ///   object and any value written through `datatype` must be valid for the elements of `buf`.
+    /// - The caller is responsible for matching the type signature of the corresponding send
+    ///   operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    unsafe fn receive_into_with_datatype_and_tag<Buf: ?Sized, D>(
+        &self,
+        buf: &mut Buf,
+        count: Count,
+        datatype: &D,
+        tag: Tag,
+    ) -> Status
+    where
+        Buf: PointerMut,
+        D: Datatype,
+    {
+        check_receive_tag(tag);
+        Status(
+            with_uninitialized(|status| {
+                ffi::MPI_Recv(
+                    buf.pointer_mut(),
+                    count,
+                    datatype.as_raw(),
+                    self.source_rank(),
+                    tag,
+                    self.as_communicator().as_raw(),
+                    status,
+                )
+            })
+            .1,
+        )
+    }
+
+    /// Receive a message into a `Pointer` with an explicit datatype.
+    ///
+    /// Receive a message from `Source` `&self` containing at most `count` instances of `datatype`
+    /// into the memory at `buf`.
+    ///
+    /// # Examples
+    /// See `examples/explicit_datatype.rs`
+    ///
+    /// # Safety
+    /// See `receive_into_with_datatype_and_tag()`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    unsafe fn receive_into_with_datatype<Buf: ?Sized, D>(
+        &self,
+        buf: &mut Buf,
+        count: Count,
+        datatype: &D,
+    ) -> Status
+    where
+        Buf: PointerMut,
+        D: Datatype,
+    {
+        self.receive_into_with_datatype_and_tag(buf, count, datatype, ffi::RSMPI_ANY_TAG)
+    }
+
+    /// Receive a message into uninitialized memory.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` containing at most `buf.len()`
+    /// instances of type `Msg` into `buf`. Returns the prefix of `buf` that has been initialized
+    /// by the received message, which can be shorter than `buf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_uninit_with_tag<'b, Msg>(
+        &self,
+        buf: &'b mut [MaybeUninit<Msg>],
+        tag: Tag,
+    ) -> (&'b mut [Msg], Status)
+    where
+        Msg: Equivalence,
+    {
+        check_receive_tag(tag);
+        let status = unsafe {
+            Status(
+                with_uninitialized(|status| {
+                    ffi::MPI_Recv(
+                        buf.as_mut_ptr() as _,
+                        buf.len()
+                            .value_as()
+                            .expect("Length of slice cannot be expressed as an MPI Count."),
+                        Msg::equivalent_datatype().as_raw(),
+                        self.source_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        status,
+                    )
+                })
+                .1,
+            )
+        };
+        let count: usize = status
+            .count(Msg::equivalent_datatype())
+            .value_as()
+            .expect("Received a message that does not contain a whole number of elements.");
+        // MPI has written exactly `count` instances of `Msg` to the start of `buf`, and since
+        // `Msg: Equivalence` their bit patterns are valid values of `Msg`.
+        let msg = unsafe { slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut Msg, count) };
+        (msg, status)
+    }
+
+    /// Receive a message into uninitialized memory.
+    ///
+    /// Receive a message from `Source` `&self` containing at most `buf.len()` instances of type
+    /// `Msg` into `buf`. Returns the prefix of `buf` that has been initialized by the received
+    /// message, which can be shorter than `buf`.
+    ///
+    /// # Examples
+    /// See `examples/receive_uninit.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_uninit<'b, Msg>(
+        &self,
+        buf: &'b mut [MaybeUninit<Msg>],
+    ) -> (&'b mut [Msg], Status)
+    where
+        Msg: Equivalence,
+    {
+        self.receive_into_uninit_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
     /// Receive a message containing multiple instances of type `Msg` into a `Vec`.
     ///
     /// Receive a message from `Source` `&self` tagged `tag` containing multiple instances of type
-    /// `Msg` into a `Vec`.
+    /// `Msg` into a `Vec`. The `Vec` is allocated to exactly fit the message, an empty message
+    /// results in an empty `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Msg` is a zero-sized type.
+    ///
+    /// # Examples
+    /// See `examples/receive_vec.rs`
     ///
     /// # Standard section(s)
     ///
@@ -254,10 +561,15 @@ pub unsafe trait Source: AsCommunicator {
     /// Receive a message containing multiple instances of type `Msg` into a `Vec`.
     ///
     /// Receive a message from `Source` `&self` containing multiple instances of type `Msg` into a
-    /// `Vec`.
+    /// `Vec`. The `Vec` is allocated to exactly fit the message, an empty message results in an
+    /// empty `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Msg` is a zero-sized type.
     ///
     /// # Examples
-    /// See `examples/send_receive.rs`
+    /// See `examples/send_receive.rs`, `examples/receive_vec.rs`
     ///
     /// # Standard section(s)
     ///
@@ -269,6 +581,85 @@ pub unsafe trait Source: AsCommunicator {
         self.receive_vec_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
     }
 
+    /// Receive a message containing a string.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` that was sent via
+    /// `Destination::send_str_with_tag()`, i.e. a message of UTF-8 encoded bytes. Returns an
+    /// error if the received bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    /// See `examples/send_string.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_string_with_tag(&self, tag: Tag) -> (Result<String, FromUtf8Error>, Status) {
+        let (bytes, status) = self.receive_vec_with_tag::<u8>(tag);
+        (String::from_utf8(bytes), status)
+    }
+
+    /// Receive a message containing a string.
+    ///
+    /// Receive a message from `Source` `&self` that was sent via `Destination::send_str()`, i.e. a
+    /// message of UTF-8 encoded bytes. Returns an error if the received bytes are not valid
+    /// UTF-8.
+    ///
+    /// # Examples
+    /// See `examples/send_string.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_string(&self) -> (Result<String, FromUtf8Error>, Status) {
+        self.receive_string_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Receive a message containing a serialized value of type `Msg`.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` that was sent via
+    /// `Destination::send_serialized_with_tag()` and deserialize it with `bincode`. Unlike
+    /// `receive_with_tag()`, this allocates a buffer for the encoded bytes and decodes them into a
+    /// new value. Returns an error if the message cannot be decoded into a `Msg`.
+    ///
+    /// # Examples
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    #[cfg(feature = "serde")]
+    fn receive_deserialized_with_tag<Msg>(
+        &self,
+        tag: Tag,
+    ) -> (Result<Msg, SerializationError>, Status)
+    where
+        Msg: DeserializeOwned,
+    {
+        let (bytes, status) = self.receive_vec_with_tag::<u8>(tag);
+        (
+            bincode::deserialize(&bytes).map_err(SerializationError),
+            status,
+        )
+    }
+
+    /// Receive a message containing a serialized value of type `Msg`.
+    ///
+    /// See `receive_deserialized_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    #[cfg(feature = "serde")]
+    fn receive_deserialized<Msg>(&self) -> (Result<Msg, SerializationError>, Status)
+    where
+        Msg: DeserializeOwned,
+    {
+        self.receive_deserialized_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
     /// Initiate an immediate (non-blocking) receive operation.
     ///
     /// Initiate receiving a message matching `tag` into `buf`.
@@ -286,6 +677,7 @@ pub unsafe trait Source: AsCommunicator {
         Buf: 'a + BufferMut,
         Sc: Scope<'a>,
     {
+        check_receive_tag(tag);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -327,6 +719,109 @@ pub unsafe trait Source: AsCommunicator {
         self.immediate_receive_into_with_tag(scope, buf, unsafe { ffi::RSMPI_ANY_TAG })
     }
 
+    /// Initiate an immediate (non-blocking) receive operation into an owned buffer.
+    ///
+    /// Initiate receiving a message matching `tag` into the elements of `buf`. The returned
+    /// `OwnedRequest` hands `buf` back once the message has been received, see `OwnedRequest` for
+    /// the tradeoffs compared to `immediate_receive_into_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_receive_owned_with_tag<T>(&self, mut buf: Vec<T>, tag: Tag) -> OwnedRequest<T>
+    where
+        T: Equivalence,
+    {
+        check_receive_tag(tag);
+        unsafe {
+            let request = with_uninitialized(|request| {
+                ffi::MPI_Irecv(
+                    buf[..].pointer_mut(),
+                    buf[..].count(),
+                    T::equivalent_datatype().as_raw(),
+                    self.source_rank(),
+                    tag,
+                    self.as_communicator().as_raw(),
+                    request,
+                )
+            })
+            .1;
+            OwnedRequest::new(Request::from_raw(request, StaticScope), buf)
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) receive operation into an owned buffer.
+    ///
+    /// Initiate receiving a message into the elements of `buf`, see
+    /// `immediate_receive_owned_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_receive_owned<T>(&self, buf: Vec<T>) -> OwnedRequest<T>
+    where
+        T: Equivalence,
+    {
+        self.immediate_receive_owned_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Receive a message matching tag `tag` into `buf` asynchronously.
+    ///
+    /// Initiates an immediate receive operation and returns a future that resolves to the
+    /// `Status` of the received message. `buf` stays borrowed until the future has completed or
+    /// has been dropped. See `RequestFuture` for how the future is driven.
+    ///
+    /// # Examples
+    /// See `examples/async_receive.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2, 3.7.3
+    #[cfg(feature = "async")]
+    fn receive_into_async_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) -> RequestFuture<'a, Sc>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        self.immediate_receive_into_with_tag(scope, buf, tag).into()
+    }
+
+    /// Receive a message into `buf` asynchronously.
+    ///
+    /// Initiates an immediate receive operation and returns a future that resolves to the
+    /// `Status` of the received message. `buf` stays borrowed until the future has completed or
+    /// has been dropped. See `RequestFuture` for how the future is driven.
+    ///
+    /// # Examples
+    /// See `examples/async_receive.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2, 3.7.3
+    #[cfg(feature = "async")]
+    fn receive_into_async<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> RequestFuture<'a, Sc>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        self.receive_into_async_with_tag(scope, buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
     /// Initiate a non-blocking receive operation for messages matching tag `tag`.
     ///
     /// # Standard section(s)
@@ -336,6 +831,7 @@ pub unsafe trait Source: AsCommunicator {
     where
         Msg: Equivalence,
     {
+        check_receive_tag(tag);
         unsafe {
             let val = alloc::alloc(Layout::new::<Msg>()) as *mut Msg;
             let (_, request) = with_uninitialized(|request| {
@@ -381,6 +877,7 @@ pub unsafe trait Source: AsCommunicator {
     ///
     /// 3.8.1
     fn immediate_probe_with_tag(&self, tag: Tag) -> Option<Status> {
+        check_receive_tag(tag);
         unsafe {
             let mut status = MaybeUninit::uninit();
 
@@ -426,6 +923,7 @@ pub unsafe trait Source: AsCommunicator {
     ///
     /// 3.8.2
     fn immediate_matched_probe_with_tag(&self, tag: Tag) -> Option<(Message, Status)> {
+        check_receive_tag(tag);
         unsafe {
             let mut message = MaybeUninit::uninit();
             let mut status = MaybeUninit::uninit();
@@ -462,6 +960,133 @@ pub unsafe trait Source: AsCommunicator {
     fn immediate_matched_probe(&self) -> Option<(Message, Status)> {
         self.immediate_matched_probe_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
     }
+
+    /// Iterate over incoming messages tagged `tag`, blocking until each one arrives.
+    ///
+    /// Every message from `Source` `&self` is probed and received as a `Vec` of `Msg` via
+    /// `matched_probe_with_tag()`, which is safe to use concurrently from several threads. The
+    /// iterator never ends by itself, use e.g. `take_while()` to stop at a sentinel message.
+    ///
+    /// # Examples
+    /// See `examples/incoming.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    fn incoming_with_tag<Msg>(&self, tag: Tag) -> Incoming<'_, Self, Msg>
+    where
+        Msg: Equivalence,
+    {
+        Incoming {
+            source: self,
+            tag,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Iterate over incoming messages with any tag, blocking until each one arrives.
+    ///
+    /// See `incoming_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/incoming.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    fn incoming<Msg>(&self) -> Incoming<'_, Self, Msg>
+    where
+        Msg: Equivalence,
+    {
+        self.incoming_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Iterate over the messages tagged `tag` that have already arrived.
+    ///
+    /// Like `incoming_with_tag()` but based on `immediate_matched_probe_with_tag()`, so the
+    /// iterator ends as soon as no matching message is pending.
+    ///
+    /// # Examples
+    /// See `examples/incoming.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    fn try_incoming_with_tag<Msg>(&self, tag: Tag) -> TryIncoming<'_, Self, Msg>
+    where
+        Msg: Equivalence,
+    {
+        TryIncoming {
+            source: self,
+            tag,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Iterate over the messages with any tag that have already arrived.
+    ///
+    /// See `try_incoming_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/incoming.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    fn try_incoming<Msg>(&self) -> TryIncoming<'_, Self, Msg>
+    where
+        Msg: Equivalence,
+    {
+        self.try_incoming_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
+    }
+}
+
+/// An endless iterator over the messages arriving from a `Source`
+///
+/// Created by `Source::incoming_with_tag()` and `Source::incoming()`.
+pub struct Incoming<'a, S: ?Sized, Msg> {
+    source: &'a S,
+    tag: Tag,
+    phantom: PhantomData<fn() -> Msg>,
+}
+
+impl<'a, S: ?Sized, Msg> Iterator for Incoming<'a, S, Msg>
+where
+    S: Source,
+    Msg: Equivalence,
+{
+    type Item = (Vec<Msg>, Status);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(
+            self.source
+                .matched_probe_with_tag(self.tag)
+                .matched_receive_vec(),
+        )
+    }
+}
+
+/// An iterator over the messages that have already arrived from a `Source`
+///
+/// Created by `Source::try_incoming_with_tag()` and `Source::try_incoming()`.
+pub struct TryIncoming<'a, S: ?Sized, Msg> {
+    source: &'a S,
+    tag: Tag,
+    phantom: PhantomData<fn() -> Msg>,
+}
+
+impl<'a, S: ?Sized, Msg> Iterator for TryIncoming<'a, S, Msg>
+where
+    S: Source,
+    Msg: Equivalence,
+{
+    type Item = (Vec<Msg>, Status);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.source
+            .immediate_matched_probe_with_tag(self.tag)
+            .map(|probed| probed.matched_receive_vec())
+    }
 }
 
 unsafe impl<'a, C> Source for AnyProcess<'a, C>
@@ -498,6 +1123,10 @@ pub trait Destination: AsCommunicator {
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
     ///
+    /// # Panics
+    ///
+    /// If the number of elements in `buf` exceeds the range of `Count`
+    ///
     /// # Standard section(s)
     ///
     /// 3.2.1
@@ -505,6 +1134,7 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        check_send_tag(tag);
         unsafe {
             ffi::MPI_Send(
                 buf.pointer(),
@@ -545,6 +1175,145 @@ pub trait Destination: AsCommunicator {
         self.send_with_tag(buf, Tag::default())
     }
 
+    /// Blocking standard mode send operation for strings
+    ///
+    /// Send `msg` to the `Destination` `&self` and tag it. The message consists of the UTF-8
+    /// encoded bytes of `msg` without any terminator or length header, since the receiver learns
+    /// the length from the size of the message. Receive it with `Source::receive_string()` or as
+    /// a `Vec<u8>`.
+    ///
+    /// # Examples
+    /// See `examples/send_string.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_str_with_tag(&self, msg: &str, tag: Tag) {
+        self.send_with_tag(msg.as_bytes(), tag)
+    }
+
+    /// Blocking standard mode send operation for strings
+    ///
+    /// Send `msg` to the `Destination` `&self`. See `send_str_with_tag()` for the format of the
+    /// message.
+    ///
+    /// # Examples
+    /// See `examples/send_string.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_str(&self, msg: &str) {
+        self.send_str_with_tag(msg, Tag::default())
+    }
+
+    /// Blocking standard mode send operation for serializable values
+    ///
+    /// Serialize `msg` with `bincode` and send the encoded bytes to the `Destination` `&self` as a
+    /// message of `u8`, tagged `tag`. The length of the encoding is conveyed by the size of the
+    /// message. This supports types without an `Equivalence`, at the cost of encoding `msg` into
+    /// a newly allocated buffer, whereas `send_with_tag()` sends directly from memory. Returns an
+    /// error if `msg` cannot be serialized, in which case nothing is sent.
+    ///
+    /// # Examples
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "serde")]
+    fn send_serialized_with_tag<Msg: ?Sized>(
+        &self,
+        msg: &Msg,
+        tag: Tag,
+    ) -> Result<(), SerializationError>
+    where
+        Msg: Serialize,
+    {
+        let bytes = bincode::serialize(msg).map_err(SerializationError)?;
+        self.send_with_tag(&bytes[..], tag);
+        Ok(())
+    }
+
+    /// Blocking standard mode send operation for serializable values
+    ///
+    /// See `send_serialized_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "serde")]
+    fn send_serialized<Msg: ?Sized>(&self, msg: &Msg) -> Result<(), SerializationError>
+    where
+        Msg: Serialize,
+    {
+        self.send_serialized_with_tag(msg, Tag::default())
+    }
+
+    /// Blocking standard mode send operation with an explicit datatype
+    ///
+    /// Send `count` instances of `datatype` starting at the address of `buf` to the `Destination`
+    /// `&self` and tag it. Unlike `send_with_tag()`, the datatype is not derived from the element
+    /// type of `buf`, e.g. to send parts of a byte buffer described by a derived datatype.
+    ///
+    /// # Examples
+    /// See `examples/explicit_datatype.rs`
+    ///
+    /// # Safety
+    /// - `count` instances of `datatype` must map `buf` without exposing any padding bytes or
+    ///   exceeding the bounds of the object.
+    /// - The caller is responsible for matching the type signature of the corresponding receive
+    ///   operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    unsafe fn send_with_datatype_and_tag<Buf: ?Sized, D>(
+        &self,
+        buf: &Buf,
+        count: Count,
+        datatype: &D,
+        tag: Tag,
+    ) where
+        Buf: Pointer,
+        D: Datatype,
+    {
+        check_send_tag(tag);
+        ffi::MPI_Send(
+            buf.pointer(),
+            count,
+            datatype.as_raw(),
+            self.destination_rank(),
+            tag,
+            self.as_communicator().as_raw(),
+        );
+    }
+
+    /// Blocking standard mode send operation with an explicit datatype
+    ///
+    /// Send `count` instances of `datatype` starting at the address of `buf` to the `Destination`
+    /// `&self`.
+    ///
+    /// # Examples
+    /// See `examples/explicit_datatype.rs`
+    ///
+    /// # Safety
+    /// See `send_with_datatype_and_tag()`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    unsafe fn send_with_datatype<Buf: ?Sized, D>(&self, buf: &Buf, count: Count, datatype: &D)
+    where
+        Buf: Pointer,
+        D: Datatype,
+    {
+        self.send_with_datatype_and_tag(buf, count, datatype, Tag::default())
+    }
+
     /// Blocking buffered mode send operation
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
@@ -556,6 +1325,7 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        check_send_tag(tag);
         unsafe {
             ffi::MPI_Bsend(
                 buf.pointer(),
@@ -595,6 +1365,7 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        check_send_tag(tag);
         unsafe {
             ffi::MPI_Ssend(
                 buf.pointer(),
@@ -636,6 +1407,7 @@ pub trait Destination: AsCommunicator {
     where
         Buf: Buffer,
     {
+        check_send_tag(tag);
         unsafe {
             ffi::MPI_Rsend(
                 buf.pointer(),
@@ -668,6 +1440,10 @@ pub trait Destination: AsCommunicator {
     ///
     /// Initiate sending the data in `buf` in standard mode and tag it.
     ///
+    /// # Panics
+    ///
+    /// If the number of elements in `buf` exceeds the range of `Count`
+    ///
     /// # Standard section(s)
     ///
     /// 3.7.2
@@ -681,6 +1457,7 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        check_send_tag(tag);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -718,6 +1495,62 @@ pub trait Destination: AsCommunicator {
         self.immediate_send_with_tag(scope, buf, Tag::default())
     }
 
+    /// Initiate an immediate (non-blocking) standard mode send operation from an owned buffer.
+    ///
+    /// Initiate sending the elements of `buf` in standard mode and tag it. The returned
+    /// `OwnedRequest` hands `buf` back once the send has completed, see `OwnedRequest` for the
+    /// tradeoffs compared to `immediate_send_with_tag()`.
+    ///
+    /// # Panics
+    ///
+    /// If the number of elements in `buf` exceeds the range of `Count`
+    ///
+    /// # Examples
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_send_owned_with_tag<T>(&self, buf: Vec<T>, tag: Tag) -> OwnedRequest<T>
+    where
+        T: Equivalence,
+    {
+        check_send_tag(tag);
+        unsafe {
+            let request = with_uninitialized(|request| {
+                ffi::MPI_Isend(
+                    buf[..].pointer(),
+                    buf[..].count(),
+                    T::equivalent_datatype().as_raw(),
+                    self.destination_rank(),
+                    tag,
+                    self.as_communicator().as_raw(),
+                    request,
+                )
+            })
+            .1;
+            OwnedRequest::new(Request::from_raw(request, StaticScope), buf)
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) standard mode send operation from an owned buffer.
+    ///
+    /// Initiate sending the elements of `buf` in standard mode, see
+    /// `immediate_send_owned_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_send_owned<T>(&self, buf: Vec<T>) -> OwnedRequest<T>
+    where
+        T: Equivalence,
+    {
+        self.immediate_send_owned_with_tag(buf, Tag::default())
+    }
+
     /// Initiate an immediate (non-blocking) buffered mode send operation.
     ///
     /// Initiate sending the data in `buf` in buffered mode and tag it.
@@ -735,6 +1568,7 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        check_send_tag(tag);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -790,6 +1624,7 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        check_send_tag(tag);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -845,6 +1680,7 @@ pub trait Destination: AsCommunicator {
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
+        check_send_tag(tag);
         unsafe {
             Request::from_raw(
                 with_uninitialized(|request| {
@@ -921,6 +1757,104 @@ impl Status {
     pub fn count<D: Datatype>(&self, d: D) -> Count {
         unsafe { with_uninitialized(|count| ffi::MPI_Get_count(&self.0, d.as_raw(), count)).1 }
     }
+
+    /// Number of instances of `T` contained in the message
+    ///
+    /// Use this to find out how much of a receive buffer has been filled, e.g. to slice the
+    /// buffer to the received length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message does not consist of a whole number of `T`.
+    ///
+    /// # Examples
+    /// See `examples/receive_count.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.5
+    pub fn count_of<T: Equivalence>(&self) -> usize {
+        self.count(T::equivalent_datatype())
+            .value_as()
+            .expect("Message does not contain a whole number of elements of the given type.")
+    }
+
+    /// Number of instances of `T` contained in the message, if it consists of a whole number of
+    /// them
+    ///
+    /// Unlike `count_of()`, this does not panic and can be used to interpret a message under
+    /// several datatypes, e.g. to check whether it can be read as an array of `T`.
+    ///
+    /// # Examples
+    /// See `examples/receive_count.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.5
+    pub fn count_as<T: Equivalence>(&self) -> Option<usize> {
+        let count = self.count(T::equivalent_datatype());
+        if count == undefined() {
+            None
+        } else {
+            count.value_as().ok()
+        }
+    }
+
+    /// Number of basic elements of the datatype equivalent to `T` contained in the message
+    ///
+    /// While `count_of()` counts whole instances of `T`, this counts the primitive elements that
+    /// make up `T`, e.g. the `i32`s inside a struct of `i32`s. This is the way to measure a
+    /// message that only partially fills the last instance of a derived datatype, for which
+    /// `count_as()` returns `None`. For a primitive `T` both counts are the same.
+    ///
+    /// Returns `mpi::undefined()` if the message does not consist of whole basic elements.
+    ///
+    /// # Examples
+    /// See `examples/get_elements.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.11
+    pub fn elements<T: Equivalence>(&self) -> Count {
+        unsafe {
+            with_uninitialized(|count| {
+                ffi::MPI_Get_elements(&self.0, T::equivalent_datatype().as_raw(), count)
+            })
+            .1
+        }
+    }
+
+    /// Number of basic elements of the datatype equivalent to `T` contained in the message as an
+    /// `MPI_Count`, see `elements()`
+    ///
+    /// Use this for messages whose element count may exceed the range of `Count`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.11
+    pub fn elements_x<T: Equivalence>(&self) -> ffi::MPI_Count {
+        unsafe {
+            with_uninitialized(|count| {
+                ffi::MPI_Get_elements_x(&self.0, T::equivalent_datatype().as_raw(), count)
+            })
+            .1
+        }
+    }
+
+    /// Size of the message in bytes
+    ///
+    /// # Examples
+    /// See `examples/receive_count.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.5
+    pub fn byte_count(&self) -> usize {
+        let byte = unsafe { DatatypeRef::from_raw(ffi::RSMPI_BYTE) };
+        self.count(byte)
+            .value_as()
+            .expect("Message size in bytes is not representable as usize.")
+    }
 }
 
 impl fmt::Debug for Status {
@@ -936,12 +1870,28 @@ impl fmt::Debug for Status {
 
 /// Describes a pending incoming message, probed by a `matched_probe()`.
 ///
+/// A `Message` is a handle that can be moved to another thread, e.g. a thread that polls with
+/// `immediate_matched_probe()` can hand matched messages to worker threads which receive them.
+/// Since the message has been removed from the matching queue by the probe, no other receive
+/// operation can intercept it in the meantime. Using a `Message` from a different thread than
+/// the one that probed it requires MPI to be initialized with `Threading::Multiple`.
+///
+/// # Panics
+///
+/// Panics if the `Message` is dropped without being received by one of the `matched_receive`
+/// methods, since the message would otherwise be lost.
+///
+/// # Examples
+/// See `examples/immediate.rs`, `examples/matched_probe_threads.rs`
+///
 /// # Standard section(s)
 ///
 /// 3.8.2
 #[must_use]
 pub struct Message(MPI_Message);
 
+unsafe impl Send for Message {}
+
 impl Message {
     /// True if the `Source` for the probe was the null process.
     pub fn is_no_proc(&self) -> bool {
@@ -1068,6 +2018,10 @@ impl Drop for Message {
 /// 3.8.3
 pub trait MatchedReceiveVec {
     /// Receives the message `&self` which contains multiple instances of type `Msg` into a `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Msg` is a zero-sized type.
     fn matched_receive_vec<Msg>(self) -> (Vec<Msg>, Status)
     where
         Msg: Equivalence;
@@ -1078,6 +2032,8 @@ impl MatchedReceiveVec for (Message, Status) {
     where
         Msg: Equivalence,
     {
+        assert_not_zero_sized::<Msg>();
+
         let (message, status) = self;
         let count = status
             .count(Msg::equivalent_datatype())
@@ -1126,6 +2082,8 @@ where
     R: Equivalence,
     S: Source,
 {
+    check_send_tag(sendtag);
+    check_receive_tag(receivetag);
     assert_eq!(
         source
             .as_communicator()
@@ -1196,6 +2154,8 @@ where
     B: BufferMut,
     S: Source,
 {
+    check_send_tag(sendtag);
+    check_receive_tag(receivetag);
     assert_eq!(
         source
             .as_communicator()
@@ -1268,6 +2228,8 @@ where
     D: Destination,
     S: Source,
 {
+    check_send_tag(sendtag);
+    check_receive_tag(receivetag);
     assert_eq!(
         source
             .as_communicator()
@@ -1355,3 +2317,161 @@ where
         }
     }
 }
+
+/// A reusable, fixed-capacity buffer for receiving messages of varying length
+///
+/// Each receive overwrites the previous contents and yields only the elements that were actually
+/// received, so stale data from an earlier, longer message is never exposed.
+///
+/// # Examples
+/// See `examples/recv_buffer.rs`
+///
+/// # Standard section(s)
+///
+/// 3.2.4, 3.2.5
+pub struct RecvBuffer<T> {
+    storage: Box<[MaybeUninit<T>]>,
+}
+
+impl<T> RecvBuffer<T>
+where
+    T: Equivalence,
+{
+    /// Allocates a buffer that can hold messages of up to `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> RecvBuffer<T> {
+        RecvBuffer {
+            storage: (0..capacity).map(|_| MaybeUninit::uninit()).collect(),
+        }
+    }
+
+    /// The maximum number of elements a message received into this buffer can contain
+    pub fn capacity(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Receives a message with tag `tag` from `source`, returning the received elements.
+    ///
+    /// The message is truncated, i.e. MPI raises an error, if it contains more than `capacity()`
+    /// elements.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    pub fn recv_from_with_tag<S: ?Sized>(&mut self, source: &S, tag: Tag) -> &[T]
+    where
+        S: Source,
+    {
+        source
+            .receive_into_uninit_with_tag(&mut self.storage[..], tag)
+            .0
+    }
+
+    /// Receives a message from `source`, returning the received elements.
+    ///
+    /// The message is truncated, i.e. MPI raises an error, if it contains more than `capacity()`
+    /// elements.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    pub fn recv_from<S: ?Sized>(&mut self, source: &S) -> &[T]
+    where
+        S: Source,
+    {
+        source.receive_into_uninit(&mut self.storage[..]).0
+    }
+}
+
+/// A channel for messages of type `T` between the processes of a communicator
+///
+/// The channel owns a duplicate of the communicator it was created from and sends all messages
+/// with a fixed tag, so its traffic can neither be intercepted by nor intercept other receive
+/// operations. Several channels can be used concurrently, e.g. one for requests and one for
+/// replies.
+///
+/// # Examples
+/// See `examples/typed_channel.rs`
+///
+/// # Standard section(s)
+///
+/// 3.5, 6.4.2
+pub struct TypedChannel<T> {
+    comm: UserCommunicator,
+    tag: Tag,
+    phantom: PhantomData<fn(T) -> T>,
+}
+
+impl<T> TypedChannel<T>
+where
+    T: Equivalence,
+{
+    /// Creates a channel for the processes of `comm` that uses tag `tag`.
+    ///
+    /// This is a collective operation on `comm` since the communicator is duplicated.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2
+    pub fn new<C: ?Sized>(comm: &C, tag: Tag) -> TypedChannel<T>
+    where
+        C: Communicator,
+    {
+        TypedChannel {
+            comm: comm.duplicate(),
+            tag,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The tag used for all messages on this channel
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// The communicator that carries the messages of this channel
+    pub fn communicator(&self) -> &UserCommunicator {
+        &self.comm
+    }
+
+    /// Sends `msg` to the process with rank `rank`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    pub fn send(&self, rank: Rank, msg: &T) {
+        self.comm.process_at_rank(rank).send_with_tag(msg, self.tag)
+    }
+
+    /// Receives a message from the process with rank `rank`, blocking until it arrives.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    pub fn recv(&self, rank: Rank) -> T {
+        self.comm.process_at_rank(rank).receive_with_tag(self.tag).0
+    }
+
+    /// Receives a message from any process, blocking until one arrives.
+    ///
+    /// Returns the message and the rank of the sender.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    pub fn recv_any(&self) -> (T, Rank) {
+        let (msg, status) = self.comm.any_process().receive_with_tag(self.tag);
+        (msg, status.source_rank())
+    }
+
+    /// Receives a message from the process with rank `rank` if one has already arrived.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    pub fn try_recv(&self, rank: Rank) -> Option<T> {
+        self.comm
+            .process_at_rank(rank)
+            .immediate_matched_probe_with_tag(self.tag)
+            .map(|(msg, _)| msg.matched_receive().0)
+    }
+}
@@ -11,7 +11,8 @@
 //!
 //! To enforce this rule, every request object must be registered to some pre-existing
 //! [`Scope`](trait.Scope.html).  At the end of a `Scope`, all its remaining requests will be waited
-//! for until completion.  Scopes can be created using either [`scope`](fn.scope.html) or
+//! for until completion.  Scopes can be created using either [`scope`](fn.scope.html),
+//! [`sync_scope`](fn.sync_scope.html) for scopes shared between threads, or
 //! [`StaticScope`](struct.StaticScope.html).
 //!
 //! To handle request completion in an RAII style, a request can be wrapped in either
@@ -19,26 +20,44 @@
 //! follow the respective policy for completing the operation.  When the guard is dropped, the
 //! request will be automatically unregistered from its `Scope`.
 //!
+//! A [`PersistentRequest`](struct.PersistentRequest.html) can be started and completed any number
+//! of times and is registered with a `Scope` for its entire lifetime.
+//!
+//! Requests with `'static` buffers can also be handed to a
+//! [`ProgressEngine`](struct.ProgressEngine.html), which completes them on a background thread.
+//!
 //! # Unfinished features
 //!
 //! - **3.7**: Nonblocking mode:
-//!   - Completion, `MPI_Waitall()`, `MPI_Waitsome()`,
+//!   - Completion, `MPI_Waitsome()`,
 //!   `MPI_Testany()`, `MPI_Testall()`, `MPI_Testsome()`, `MPI_Request_get_status()`
 //! - **3.8**:
 //!   - Cancellation, `MPI_Test_cancelled()`
 
 use std::cell::Cell;
 use std::convert::TryInto;
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::marker::PhantomData;
 use std::mem::{self, MaybeUninit};
+#[cfg(feature = "async")]
+use std::pin::Pin;
 use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use crate::ffi;
 use crate::ffi::{MPI_Request, MPI_Status};
 
-use crate::point_to_point::Status;
+use crate::datatype::traits::*;
+use crate::environment::Universe;
+use crate::point_to_point::{Destination, Source, Status};
 use crate::raw::traits::*;
-use crate::with_uninitialized;
+use crate::{with_uninitialized, Tag, Threading};
 
 /// Check if the request is `MPI_REQUEST_NULL`.
 fn is_null(request: MPI_Request) -> bool {
@@ -261,6 +280,297 @@ impl<'a, S: Scope<'a>> Request<'a, S> {
             Request::from_raw(request, scope)
         }
     }
+
+    /// Move the request from its scope to the `StaticScope`.
+    ///
+    /// This is the reverse of `shrink_scope_to()`. The request is unregistered from its current
+    /// scope, which can then end while the operation is still in progress.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate.rs`
+    ///
+    /// # Safety
+    /// - All buffers associated with the request must stay alive and must not be accessed (or in
+    ///   the case of send buffers modified) until the returned request has completed, even
+    ///   though their lifetime is no longer tracked by the compiler.
+    /// - The returned request must be completed, dropping it panics like any other `Request`.
+    pub unsafe fn promote_to_static(self) -> Request<'static, StaticScope> {
+        let (request, _) = self.into_raw();
+        Request::from_raw(request, StaticScope)
+    }
+}
+
+/// A request object for a non-blocking operation that owns its buffer
+///
+/// Returned by operations like `immediate_send_owned()` that take ownership of a `Vec` instead
+/// of borrowing a buffer for the lifetime of a `Scope`. The `Vec` is handed back when the
+/// operation completes. Moving the `OwnedRequest` around does not move the elements of the `Vec`,
+/// so the request is not tied to any scope and can e.g. be stored in a collection.
+///
+/// This trades the borrow checked buffer of `Request` for a heap allocation that stays alive
+/// until the operation has completed, even if the elements are not needed afterwards.
+///
+/// # Panics
+///
+/// Aborts the program if the request object is dropped before the operation has completed, since
+/// MPI may still access the buffer.
+///
+/// # Examples
+///
+/// See `examples/immediate_owned.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.1
+#[must_use]
+#[derive(Debug)]
+pub struct OwnedRequest<T> {
+    inner: Option<(Request<'static>, Vec<T>)>,
+}
+
+impl<T> OwnedRequest<T> {
+    /// Pairs a request with the buffer it operates on.
+    ///
+    /// # Safety
+    /// `request` must not access any memory other than the elements of `buffer`.
+    pub(crate) unsafe fn new(request: Request<'static>, buffer: Vec<T>) -> Self {
+        OwnedRequest {
+            inner: Some((request, buffer)),
+        }
+    }
+
+    /// Wait for the operation to finish and return the buffer.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait(mut self) -> (Vec<T>, Status) {
+        let (request, buffer) = self.inner.take().expect("request has already completed");
+        let status = request.wait();
+        (buffer, status)
+    }
+
+    /// Test whether the operation has finished.
+    ///
+    /// If the operation has finished, the buffer and the `Status` are returned. Otherwise returns
+    /// the unfinished `OwnedRequest`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test(mut self) -> Result<(Vec<T>, Status), Self> {
+        let (request, buffer) = self.inner.take().expect("request has already completed");
+        match request.test() {
+            Ok(status) => Ok((buffer, status)),
+            Err(request) => {
+                self.inner = Some((request, buffer));
+                Err(self)
+            }
+        }
+    }
+}
+
+impl<T> Drop for OwnedRequest<T> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            abort_on_unhandled_request();
+        }
+    }
+}
+
+/// A persistent request that can be started and completed repeatedly
+///
+/// Persistent operations bind the arguments of an operation, e.g. its buffers, once and can then
+/// be started any number of times, which saves the set-up cost of the operation in loops that
+/// repeat the same communication. The request is inactive after its creation. `start()` initiates
+/// the operation and `wait()` or `test()` complete it, after which it can be started again.
+///
+/// The request holds on to the borrows `B` of the buffers it is bound to. For requests with a send
+/// and a receive buffer, their contents are accessible via `buffers()` and `buffers_mut()` while
+/// the request is inactive, e.g. to update the data to send before the next start. The borrows
+/// themselves are only handed back by `free()`. Like `Request`, a `PersistentRequest`
+/// is registered with a `Scope` to ensure that the buffers outlive the request even if its
+/// destructor does not run. Dropping a `PersistentRequest` waits for an active operation to
+/// complete before freeing it.
+///
+/// # Examples
+///
+/// See `examples/persistent_all_reduce.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+#[must_use]
+#[derive(Debug)]
+pub struct PersistentRequest<'a, B, S: Scope<'a> = StaticScope> {
+    request: MPI_Request,
+    active: bool,
+    buffers: B,
+    scope: S,
+    phantom: PhantomData<Cell<&'a ()>>,
+}
+
+impl<'a, B, S: Scope<'a>> PersistentRequest<'a, B, S> {
+    /// Construct a persistent request object from the raw MPI type and the borrows of the buffers
+    /// it is bound to.
+    ///
+    /// # Safety
+    /// - `request` must be a live, inactive, persistent request.
+    /// - `request` must not be used after calling `from_raw`.
+    /// - `buffers` must hold the borrows of all buffers bound to `request`.
+    /// - Any buffers bound to `request` must live longer than `scope`.
+    pub unsafe fn from_raw(request: MPI_Request, buffers: B, scope: S) -> Self {
+        debug_assert!(!is_null(request));
+        scope.register();
+        PersistentRequest {
+            request,
+            active: false,
+            buffers,
+            scope,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Whether the operation has been started and not yet completed
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Start the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation is already active.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start(&mut self) {
+        assert!(!self.active, "persistent request has already been started");
+        unsafe {
+            ffi::MPI_Start(&mut self.request);
+        }
+        self.active = true;
+    }
+
+    /// Wait for the started operation to finish.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation has not been started.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 3.9
+    pub fn wait(&mut self) -> Status {
+        assert!(self.active, "persistent request has not been started");
+        let status = unsafe {
+            Status::from_raw(
+                with_uninitialized(|status| ffi::MPI_Wait(&mut self.request, status)).1,
+            )
+        };
+        self.active = false;
+        status
+    }
+
+    /// Test whether the started operation has finished.
+    ///
+    /// Returns the `Status` if it has, after which the request is inactive again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation has not been started.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 3.9
+    pub fn test(&mut self) -> Option<Status> {
+        assert!(self.active, "persistent request has not been started");
+        unsafe {
+            let mut status = MaybeUninit::uninit();
+            let (_, flag) = with_uninitialized(|flag| {
+                ffi::MPI_Test(&mut self.request, flag, status.as_mut_ptr())
+            });
+            if flag != 0 {
+                self.active = false;
+                Some(Status::from_raw(status.assume_init()))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Free the request, waiting for an active operation to complete first, and return the
+    /// buffers.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn free(mut self) -> B {
+        self.release();
+        unsafe {
+            let buffers = ptr::read(&self.buffers);
+            let _ = ptr::read(&self.scope);
+            mem::forget(self);
+            buffers
+        }
+    }
+
+    fn release(&mut self) {
+        if self.active {
+            self.wait();
+        }
+        unsafe {
+            ffi::MPI_Request_free(&mut self.request);
+            self.scope.unregister();
+        }
+    }
+}
+
+impl<'a, S: ?Sized, R: ?Sized, Sc: Scope<'a>> PersistentRequest<'a, (&'a mut S, &'a mut R), Sc> {
+    /// The send and receive buffers bound to the request
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation is active.
+    pub fn buffers(&self) -> (&S, &R) {
+        assert!(
+            !self.active,
+            "buffers of an active persistent request are in use"
+        );
+        (&*self.buffers.0, &*self.buffers.1)
+    }
+
+    /// The send and receive buffers bound to the request, e.g. to update the data to send before
+    /// the next start
+    ///
+    /// Only the contents of the buffers can be accessed, the buffers the request is bound to
+    /// cannot be exchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operation is active.
+    pub fn buffers_mut(&mut self) -> (&mut S, &R) {
+        assert!(
+            !self.active,
+            "buffers of an active persistent request are in use"
+        );
+        (&mut *self.buffers.0, &*self.buffers.1)
+    }
+}
+
+unsafe impl<'a, B, S: Scope<'a>> AsRaw for PersistentRequest<'a, B, S> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.request
+    }
+}
+
+impl<'a, B, S: Scope<'a>> Drop for PersistentRequest<'a, B, S> {
+    fn drop(&mut self) {
+        self.release();
+    }
 }
 
 /// Guard object that waits for the completion of an operation when it is dropped
@@ -345,8 +655,63 @@ impl<'a, S: Scope<'a>> From<Request<'a, S>> for CancelGuard<'a, S> {
     }
 }
 
-/// A common interface for [`LocalScope`](struct.LocalScope.html) and
-/// [`StaticScope`](struct.StaticScope.html) used internally by the `request` module.
+/// A future that resolves to the `Status` of a request once the operation has finished
+///
+/// The future is driven by testing the request with `MPI_Test()` whenever it is polled. Since MPI
+/// does not provide a way to be notified about completion, a pending future immediately asks to be
+/// polled again, i.e. the executor keeps polling it in between running other tasks.
+///
+/// The future can be constructed from a `Request` using the `From` and `Into` traits. If the future
+/// is dropped before the operation has finished, the operation is cancelled and waited for like
+/// with a `CancelGuard`.
+///
+/// # Examples
+///
+/// See `examples/async_receive.rs`
+#[cfg(feature = "async")]
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct RequestFuture<'a, S: Scope<'a> = StaticScope>(Option<Request<'a, S>>);
+
+#[cfg(feature = "async")]
+impl<'a, S: Scope<'a>> Unpin for RequestFuture<'a, S> {}
+
+#[cfg(feature = "async")]
+impl<'a, S: Scope<'a>> Future for RequestFuture<'a, S> {
+    type Output = Status;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Status> {
+        let request = self.0.take().expect("invalid RequestFuture");
+        match request.test() {
+            Ok(status) => Poll::Ready(status),
+            Err(request) => {
+                self.0 = Some(request);
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, S: Scope<'a>> Drop for RequestFuture<'a, S> {
+    fn drop(&mut self) {
+        if let Some(request) = self.0.take() {
+            request.cancel();
+            request.wait_without_status();
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, S: Scope<'a>> From<Request<'a, S>> for RequestFuture<'a, S> {
+    fn from(req: Request<'a, S>) -> Self {
+        RequestFuture(Some(req))
+    }
+}
+
+/// A common interface for [`LocalScope`](struct.LocalScope.html),
+/// [`SyncScope`](struct.SyncScope.html) and [`StaticScope`](struct.StaticScope.html) used internally by the `request` module.
 ///
 /// This trait is an implementation detail.  You shouldn’t have to use or implement this trait.
 pub unsafe trait Scope<'a> {
@@ -400,7 +765,7 @@ pub struct LocalScope<'a> {
 }
 
 #[cold]
-fn abort_on_unhandled_request() {
+pub(crate) fn abort_on_unhandled_request() {
     let _ = std::panic::catch_unwind(|| {
         panic!("at least one request was dropped without being completed");
     });
@@ -463,3 +828,443 @@ where
         phantom: Default::default(),
     })
 }
+
+/// A temporary scope that lasts no more than the lifetime `'a` and can be shared between threads
+///
+/// Unlike `LocalScope`, requests can be registered with and unregistered from a `SyncScope` from
+/// several threads at once, e.g. threads that each post non-blocking operations on buffers that
+/// outlive the scope. Posting operations from several threads requires MPI to be initialized
+/// with `Threading::Multiple`.
+///
+/// To obtain a `SyncScope`, use the [`sync_scope`](fn.sync_scope.html) function.
+///
+/// # Invariant
+///
+/// For any `Request` registered with a `SyncScope<'a>`, its associated buffers must outlive `'a`.
+///
+/// # Panics
+///
+/// When `SyncScope` is dropped, it will panic if there are any lingering `Requests` that have not
+/// yet been completed.
+///
+/// # Examples
+///
+/// See `examples/sync_scope.rs`
+#[derive(Debug)]
+pub struct SyncScope<'a> {
+    num_requests: AtomicUsize,
+    phantom: PhantomData<fn(&'a ()) -> &'a ()>, // fn needed to ensure 'a is invariant and Sync
+}
+
+impl<'a> Drop for SyncScope<'a> {
+    fn drop(&mut self) {
+        if *self.num_requests.get_mut() != 0 {
+            abort_on_unhandled_request();
+        }
+    }
+}
+
+unsafe impl<'a, 'b> Scope<'a> for &'b SyncScope<'a> {
+    fn register(&self) {
+        self.num_requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    unsafe fn unregister(&self) {
+        self.num_requests
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+            .expect("unregister has been called more times than register");
+    }
+}
+
+/// Used to create a [`SyncScope`](struct.SyncScope.html)
+///
+/// Like [`scope`](fn.scope.html), but the scope passed to the closure can be shared between
+/// threads, e.g. threads spawned via `std::thread::scope()`.
+///
+/// # Examples
+///
+/// See `examples/sync_scope.rs`
+pub fn sync_scope<'a, F, R>(f: F) -> R
+where
+    F: FnOnce(&SyncScope<'a>) -> R,
+{
+    f(&SyncScope {
+        num_requests: Default::default(),
+        phantom: Default::default(),
+    })
+}
+
+/// A collection of requests that are completed together
+///
+/// A `Batch` collects the requests of several non-blocking operations registered with the same
+/// scope, e.g. all sends and receives of a halo exchange, and completes all of them at once.
+///
+/// # Aborts
+///
+/// Aborts the process if the `Batch` is dropped while it still contains requests, like a
+/// `LocalScope` would, since MPI may still access the buffers of the requests.
+///
+/// # Examples
+///
+/// See `examples/batch.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.5
+#[must_use]
+#[derive(Debug)]
+pub struct Batch<'a, S: Scope<'a> = StaticScope> {
+    requests: Vec<Request<'a, S>>,
+    scope: S,
+}
+
+impl<'a, S: Scope<'a> + Copy> Batch<'a, S> {
+    /// Creates an empty batch of requests registered with `scope`.
+    pub fn new(scope: S) -> Self {
+        Batch {
+            requests: Vec::new(),
+            scope,
+        }
+    }
+
+    /// Adds a request to the batch.
+    pub fn push(&mut self, request: Request<'a, S>) {
+        self.requests.push(request);
+    }
+
+    /// Initiates an immediate send of `buf` to `destination` with tag `tag` as part of the batch.
+    pub fn send_with_tag<D, Buf: ?Sized>(&mut self, destination: &D, buf: &'a Buf, tag: Tag)
+    where
+        D: Destination,
+        Buf: 'a + Buffer,
+    {
+        let request = destination.immediate_send_with_tag(self.scope, buf, tag);
+        self.push(request);
+    }
+
+    /// Initiates an immediate send of `buf` to `destination` as part of the batch.
+    pub fn send<D, Buf: ?Sized>(&mut self, destination: &D, buf: &'a Buf)
+    where
+        D: Destination,
+        Buf: 'a + Buffer,
+    {
+        let request = destination.immediate_send(self.scope, buf);
+        self.push(request);
+    }
+
+    /// Initiates an immediate receive of a message matching `tag` from `source` into `buf` as
+    /// part of the batch.
+    pub fn receive_into_with_tag<Src, Buf: ?Sized>(
+        &mut self,
+        source: &Src,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) where
+        Src: Source,
+        Buf: 'a + BufferMut,
+    {
+        let request = source.immediate_receive_into_with_tag(self.scope, buf, tag);
+        self.push(request);
+    }
+
+    /// Initiates an immediate receive of a message from `source` into `buf` as part of the
+    /// batch.
+    pub fn receive_into<Src, Buf: ?Sized>(&mut self, source: &Src, buf: &'a mut Buf)
+    where
+        Src: Source,
+        Buf: 'a + BufferMut,
+    {
+        let request = source.immediate_receive_into(self.scope, buf);
+        self.push(request);
+    }
+
+    /// Initiates an immediate receive from `source` into `recvbuf` and an immediate send of
+    /// `sendbuf` to `destination` as part of the batch.
+    ///
+    /// Like `point_to_point::send_receive_into()`, the pair cannot deadlock when every process of a ring
+    /// or halo exchange posts it, but the operations only have to complete on `wait_all()`. The
+    /// receive is added to the batch before the send, so its status comes first.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2, 3.7.3
+    pub fn send_receive<D, Src, S2: ?Sized, R: ?Sized>(
+        &mut self,
+        sendbuf: &'a S2,
+        destination: &D,
+        recvbuf: &'a mut R,
+        source: &Src,
+    ) where
+        D: Destination,
+        Src: Source,
+        S2: 'a + Buffer,
+        R: 'a + BufferMut,
+    {
+        self.receive_into(source, recvbuf);
+        self.send(destination, sendbuf);
+    }
+}
+
+impl<'a, S: Scope<'a>> Batch<'a, S> {
+    /// The number of requests in the batch
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// True if the batch does not contain any requests
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Wait for all operations in the batch to finish.
+    ///
+    /// Returns the `Status` of every operation in the order the requests were added.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5
+    pub fn wait_all(mut self) -> Vec<Status> {
+        let mut requests: Vec<_> = self
+            .requests
+            .drain(..)
+            .map(|request| unsafe { request.into_raw().0 })
+            .collect();
+        let count = requests
+            .len()
+            .try_into()
+            .expect("Number of requests exceeds the range of a Count.");
+        let mut statuses: Vec<MPI_Status> = Vec::with_capacity(requests.len());
+        unsafe {
+            ffi::MPI_Waitall(count, requests.as_mut_ptr(), statuses.as_mut_ptr());
+            // MPI_Waitall has filled in the status of every request.
+            statuses.set_len(requests.len());
+        }
+        assert!(requests.into_iter().all(is_null)); // persistent requests are not supported
+        statuses.into_iter().map(Status::from_raw).collect()
+    }
+
+    /// Initiate cancellation of all operations in the batch and wait for them to finish.
+    ///
+    /// See `Request::cancel()` for the limitations of cancellation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.4
+    pub fn cancel_all(self) {
+        for request in &self.requests {
+            request.cancel();
+        }
+        self.wait_all();
+    }
+}
+
+impl<'a, S: Scope<'a>> Drop for Batch<'a, S> {
+    fn drop(&mut self) {
+        if !self.requests.is_empty() {
+            abort_on_unhandled_request();
+        }
+    }
+}
+
+impl<'a> LocalScope<'a> {
+    /// Collects the requests posted by `f` into a `Batch` registered with this scope.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/batch.rs`
+    pub fn batch<'b, F>(&'b self, f: F) -> Batch<'a, &'b LocalScope<'a>>
+    where
+        F: FnOnce(&mut Batch<'a, &'b LocalScope<'a>>),
+    {
+        let mut batch = Batch::new(self);
+        f(&mut batch);
+        batch
+    }
+
+    /// Initiates an immediate send of `sendbuf` to `destination` and an immediate receive from
+    /// `source` into `recvbuf` and returns both requests as a `Batch`.
+    ///
+    /// See `Batch::send_receive()`. More operations can be added to the returned batch before
+    /// waiting for all of them with `wait_all()`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/batch.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2, 3.7.3
+    pub fn send_receive_immediate<'b, D, Src, S, R>(
+        &'b self,
+        sendbuf: &'a S,
+        destination: &D,
+        recvbuf: &'a mut R,
+        source: &Src,
+    ) -> Batch<'a, &'b LocalScope<'a>>
+    where
+        D: Destination,
+        Src: Source,
+        S: 'a + ?Sized + Buffer,
+        R: 'a + ?Sized + BufferMut,
+    {
+        let mut batch = Batch::new(self);
+        batch.send_receive(sendbuf, destination, recvbuf, source);
+        batch
+    }
+}
+
+/// Drives progress of non-blocking operations from a background thread
+///
+/// Many MPI implementations only advance non-blocking operations while the application is inside
+/// the MPI library. A `ProgressEngine` spawns a thread that periodically tests all requests
+/// registered with it, so that computation and communication can overlap without having to
+/// sprinkle calls to `test()` over the computation. When a registered operation finishes, its
+/// `Status` is sent to the corresponding `Progress` handle.
+///
+/// Since requests are completed from a different thread, MPI has to be initialized with
+/// `Threading::Multiple` and only requests with `'static` buffers can be registered. When the
+/// engine is dropped, it waits for all remaining registered requests to finish.
+///
+/// # Examples
+///
+/// See `examples/progress_engine.rs`
+#[derive(Debug)]
+pub struct ProgressEngine<'u> {
+    state: Arc<Mutex<ProgressState>>,
+    thread: Option<JoinHandle<()>>,
+    phantom: PhantomData<&'u Universe>,
+}
+
+#[derive(Debug)]
+struct ProgressState {
+    next_id: usize,
+    requests: Vec<(usize, Request<'static>, mpsc::Sender<Status>)>,
+    shutdown: bool,
+}
+
+// The MPI library is initialized with `Threading::Multiple` while a `ProgressEngine` exists, so
+// its requests can be completed from any thread.
+unsafe impl Send for ProgressState {}
+
+impl<'u> ProgressEngine<'u> {
+    /// Spawns a thread that tests the registered requests every `interval`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the MPI library does not provide `Threading::Multiple`.
+    pub fn new(universe: &'u Universe, interval: Duration) -> ProgressEngine<'u> {
+        assert_eq!(
+            universe.threading_support(),
+            Threading::Multiple,
+            "A ProgressEngine requires MPI to be initialized with Threading::Multiple."
+        );
+
+        let state = Arc::new(Mutex::new(ProgressState {
+            next_id: 0,
+            requests: Vec::new(),
+            shutdown: false,
+        }));
+
+        let thread_state = Arc::clone(&state);
+        let thread = thread::spawn(move || loop {
+            {
+                let mut state = thread_state.lock().unwrap();
+                for (id, request, sender) in mem::take(&mut state.requests) {
+                    match request.test() {
+                        Ok(status) => {
+                            let _ = sender.send(status);
+                        }
+                        Err(request) => state.requests.push((id, request, sender)),
+                    }
+                }
+                if state.shutdown {
+                    for (_, request, sender) in state.requests.drain(..) {
+                        let _ = sender.send(request.wait());
+                    }
+                    return;
+                }
+            }
+            thread::sleep(interval);
+        });
+
+        ProgressEngine {
+            state,
+            thread: Some(thread),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Hands `request` over to the engine, which completes it in the background.
+    ///
+    /// The returned `Progress` receives the `Status` of the operation once it has finished.
+    pub fn register(&self, request: Request<'static>) -> Progress {
+        let (sender, receiver) = mpsc::channel();
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.requests.push((id, request, sender));
+        Progress { id, receiver }
+    }
+
+    /// Takes a request back from the engine.
+    ///
+    /// Returns the `Request` if the operation has not finished yet, otherwise returns its
+    /// `Status`.
+    pub fn unregister(&self, progress: Progress) -> Result<Request<'static>, Status> {
+        let mut state = self.state.lock().unwrap();
+        let index = state.requests.iter().position(|r| r.0 == progress.id);
+        match index {
+            Some(index) => Ok(state.requests.swap_remove(index).1),
+            None => {
+                drop(state);
+                Err(progress.wait())
+            }
+        }
+    }
+}
+
+impl<'u> Drop for ProgressEngine<'u> {
+    fn drop(&mut self) {
+        self.state.lock().unwrap().shutdown = true;
+        if let Some(thread) = self.thread.take() {
+            thread
+                .join()
+                .expect("ProgressEngine thread panicked while completing requests");
+        }
+    }
+}
+
+/// Receives the `Status` of a request registered with a `ProgressEngine`
+///
+/// # Examples
+///
+/// See `examples/progress_engine.rs`
+#[must_use]
+#[derive(Debug)]
+pub struct Progress {
+    id: usize,
+    receiver: mpsc::Receiver<Status>,
+}
+
+impl Progress {
+    /// Blocks until the operation has finished and returns its `Status`.
+    pub fn wait(self) -> Status {
+        self.receiver
+            .recv()
+            .expect("ProgressEngine stopped without completing the request")
+    }
+
+    /// Checks whether the operation has finished.
+    ///
+    /// If the operation has finished, its `Status` is returned. Otherwise the `Progress` itself is
+    /// returned.
+    pub fn test(self) -> Result<Status, Self> {
+        match self.receiver.try_recv() {
+            Ok(status) => Ok(status),
+            Err(mpsc::TryRecvError::Empty) => Err(self),
+            Err(mpsc::TryRecvError::Disconnected) => {
+                panic!("ProgressEngine stopped without completing the request")
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::marker::PhantomData;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+use crate::ffi::MPI_Comm;
+use crate::{ffi, raw::traits::*, with_uninitialized};
+
+/// A key for caching values of type `T` on communicators
+///
+/// Libraries can use a `CommKeyval` to attach their own state to a communicator that they have
+/// been handed by the application, see `Communicator::set_attr()`. Values are boxed and owned by
+/// the communicator. They are dropped when the attribute is deleted or replaced or when the
+/// communicator is freed.
+///
+/// # Examples
+/// See `examples/attributes.rs`
+///
+/// # Standard section(s)
+///
+/// 6.7.2
+pub struct CommKeyval<T> {
+    keyval: c_int,
+    phantom: PhantomData<*const T>,
+}
+
+impl<T: Clone + 'static> CommKeyval<T> {
+    /// Creates a new key whose values are cloned when a communicator is duplicated.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.7.2
+    pub fn new() -> CommKeyval<T> {
+        CommKeyval::with_copy_fn(Some(copy_attr::<T>))
+    }
+}
+
+impl<T: 'static> CommKeyval<T> {
+    /// Creates a new key whose values are not propagated when a communicator is duplicated.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.7.2
+    pub fn without_copy() -> CommKeyval<T> {
+        CommKeyval::with_copy_fn(Some(no_copy_attr))
+    }
+
+    fn with_copy_fn(copy_fn: ffi::MPI_Comm_copy_attr_function) -> CommKeyval<T> {
+        let keyval = unsafe {
+            with_uninitialized(|keyval| {
+                ffi::MPI_Comm_create_keyval(
+                    copy_fn,
+                    Some(delete_attr::<T>),
+                    keyval,
+                    ptr::null_mut(),
+                )
+            })
+            .1
+        };
+        CommKeyval {
+            keyval,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone + 'static> Default for CommKeyval<T> {
+    fn default() -> Self {
+        CommKeyval::new()
+    }
+}
+
+impl<T> Drop for CommKeyval<T> {
+    fn drop(&mut self) {
+        // Values that are still attached to communicators stay valid, MPI only marks the key as
+        // freed and keeps calling the delete function for them.
+        unsafe {
+            ffi::MPI_Comm_free_keyval(&mut self.keyval);
+        }
+    }
+}
+
+unsafe impl<T> AsRaw for CommKeyval<T> {
+    type Raw = c_int;
+    fn as_raw(&self) -> Self::Raw {
+        self.keyval
+    }
+}
+
+/// Defines the callbacks that MPI uses to copy and delete attribute values with calling
+/// convention `$abi`
+macro_rules! attribute_callbacks {
+    ($abi:tt) => {
+        unsafe extern $abi fn copy_attr<T: Clone>(
+            _oldcomm: MPI_Comm,
+            _keyval: c_int,
+            _extra_state: *mut c_void,
+            attribute_val_in: *mut c_void,
+            attribute_val_out: *mut c_void,
+            flag: *mut c_int,
+        ) -> c_int {
+            let value = (*(attribute_val_in as *const T)).clone();
+            *(attribute_val_out as *mut *mut T) = Box::into_raw(Box::new(value));
+            *flag = 1;
+            ffi::RSMPI_SUCCESS
+        }
+
+        unsafe extern $abi fn no_copy_attr(
+            _oldcomm: MPI_Comm,
+            _keyval: c_int,
+            _extra_state: *mut c_void,
+            _attribute_val_in: *mut c_void,
+            _attribute_val_out: *mut c_void,
+            flag: *mut c_int,
+        ) -> c_int {
+            *flag = 0;
+            ffi::RSMPI_SUCCESS
+        }
+
+        unsafe extern $abi fn delete_attr<T>(
+            _comm: MPI_Comm,
+            _keyval: c_int,
+            attribute_val: *mut c_void,
+            _extra_state: *mut c_void,
+        ) -> c_int {
+            drop(Box::from_raw(attribute_val as *mut T));
+            ffi::RSMPI_SUCCESS
+        }
+    };
+}
+
+// MS-MPI uses "stdcall" rather than "C" calling convention on 32-bit x86.
+#[cfg(not(all(msmpi, target_arch = "x86")))]
+attribute_callbacks!("C");
+#[cfg(all(msmpi, target_arch = "x86"))]
+attribute_callbacks!("stdcall");
@@ -451,7 +451,15 @@ impl CartesianCommunicator {
     }
 }
 
-impl Communicator for CartesianCommunicator {}
+impl Communicator for CartesianCommunicator {
+    fn size(&self) -> Rank {
+        self.0.size()
+    }
+
+    fn rank(&self) -> Rank {
+        self.0.rank()
+    }
+}
 
 impl AsCommunicator for CartesianCommunicator {
     type Out = CartesianCommunicator;
@@ -0,0 +1,182 @@
+use std::marker::PhantomData;
+
+use conv::ConvUtil;
+
+use super::{proc_null, CartesianCommunicator, Rank};
+use crate::datatype::{Order, UserDatatype};
+use crate::{datatype::traits::*, ffi, raw::traits::*, Count, Tag};
+
+/// Exchanges the ghost cells of a local block of a distributed n-dimensional array
+///
+/// The array is distributed across the processes of a `CartesianCommunicator` in blocks, one
+/// block per process. Every block is surrounded by a border of ghost cells of a configurable
+/// width, which hold copies of the outermost cells of the neighbouring blocks. `exchange()` fills
+/// the ghost cells by sending the outermost cells of the local block to the neighbours in every
+/// dimension.
+///
+/// The dimensions are exchanged one after the other and the exchange in every dimension includes
+/// the ghost cells of the other dimensions, so the ghost cells in the corners are filled as well.
+/// Ghost cells on the boundary of a non-periodic dimension are left untouched.
+///
+/// The datatypes describing the non-contiguous faces of the block are constructed once in `new()`
+/// and reused by every call to `exchange()`.
+///
+/// # Examples
+/// See `examples/halo_exchange.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.3, 7.5.6
+pub struct HaloExchange<'c, T> {
+    comm: &'c CartesianCommunicator,
+    len: usize,
+    faces: Vec<Face>,
+    phantom: PhantomData<*const T>,
+}
+
+/// The exchange in one dimension
+struct Face {
+    lower: Rank,
+    upper: Rank,
+    send_lower: UserDatatype,
+    send_upper: UserDatatype,
+    receive_lower: UserDatatype,
+    receive_upper: UserDatatype,
+}
+
+impl<'c, T: Equivalence> HaloExchange<'c, T> {
+    /// Prepares the exchange of ghost cells that are `width` cells wide for a local block of
+    /// shape `sizes`, including the ghost cells, stored in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sizes` does not have an entry for every dimension of `comm` or if a block is too
+    /// small to hold `width` interior cells in addition to the ghost cells on both sides.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3, 7.5.6
+    pub fn new(comm: &'c CartesianCommunicator, sizes: &[Count], width: Count) -> Self {
+        assert_eq!(
+            comm.num_dimensions(),
+            sizes.count(),
+            "Sizes must have an entry for every dimension of the communicator."
+        );
+        assert!(width > 0, "Ghost cell width must be positive.");
+        assert!(
+            sizes.iter().all(|&size| size >= 3 * width),
+            "Block of size {:?} is too small for ghost cells of width {}.",
+            sizes,
+            width
+        );
+
+        let len = sizes
+            .iter()
+            .map(|&size| size.value_as::<usize>().unwrap())
+            .product();
+
+        let slab = |dimension: usize, start: Count| {
+            let mut subsizes = sizes.to_vec();
+            let mut starts = vec![0; sizes.len()];
+            subsizes[dimension] = width;
+            starts[dimension] = start;
+            UserDatatype::subarray(
+                sizes,
+                &subsizes,
+                &starts,
+                Order::C,
+                &T::equivalent_datatype(),
+            )
+        };
+
+        let faces = (0..sizes.len())
+            .map(|dimension| {
+                let size = sizes[dimension];
+                let (lower, upper) = comm.shift(dimension as Count, 1);
+                Face {
+                    lower: lower.unwrap_or_else(proc_null),
+                    upper: upper.unwrap_or_else(proc_null),
+                    send_lower: slab(dimension, width),
+                    send_upper: slab(dimension, size - 2 * width),
+                    receive_lower: slab(dimension, 0),
+                    receive_upper: slab(dimension, size - width),
+                }
+            })
+            .collect();
+
+        HaloExchange {
+            comm,
+            len,
+            faces,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Fills the ghost cells of `block` with the outermost cells of the neighbouring blocks.
+    ///
+    /// This is a collective operation, all processes of the communicator have to call it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `block` does not match the `sizes` passed to `new()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.10
+    pub fn exchange(&self, block: &mut [T]) {
+        assert_eq!(
+            self.len,
+            block.len(),
+            "Block does not have the size the exchange has been prepared for."
+        );
+        let ptr = block.as_mut_ptr();
+        for (dimension, face) in self.faces.iter().enumerate() {
+            let tag = 2 * dimension as Tag;
+            // Sending and receiving parts of the same block is fine, the send and receive
+            // datatypes of every call describe disjoint cells.
+            unsafe {
+                self.send_receive(
+                    ptr,
+                    &face.send_upper,
+                    face.upper,
+                    &face.receive_lower,
+                    face.lower,
+                    tag,
+                );
+                self.send_receive(
+                    ptr,
+                    &face.send_lower,
+                    face.lower,
+                    &face.receive_upper,
+                    face.upper,
+                    tag + 1,
+                );
+            }
+        }
+    }
+
+    unsafe fn send_receive(
+        &self,
+        block: *mut T,
+        send_type: &UserDatatype,
+        destination: Rank,
+        receive_type: &UserDatatype,
+        source: Rank,
+        tag: Tag,
+    ) {
+        ffi::MPI_Sendrecv(
+            block as *const _,
+            1,
+            send_type.as_raw(),
+            destination,
+            tag,
+            block as *mut _,
+            1,
+            receive_type.as_raw(),
+            source,
+            tag,
+            self.comm.as_raw(),
+            ffi::RSMPI_STATUS_IGNORE,
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::mem;
+use std::os::raw::c_int;
+
+use super::{AsCommunicator, Communicator, Rank, UserCommunicator, UserGroup};
+use crate::ffi::MPI_Comm;
+use crate::{ffi, raw::traits::*, with_uninitialized};
+
+/// An `InterCommunicator` connects two disjoint groups of processes, the local group that the
+/// calling process is a member of and the remote group.
+///
+/// Ranks used in point to point communication on an `InterCommunicator` refer to processes in the
+/// remote group, i.e. `process_at_rank()` addresses remote processes. `rank()` and `size()` refer
+/// to the local group. Collective operations have different semantics on inter-communicators than
+/// on intra-communicators, e.g. a broadcast transfers data from a root in one group to all
+/// processes in the other group. Use `merge()` to obtain an intra-communicator that spans both
+/// groups.
+///
+/// # Examples
+/// See `examples/intercommunicator.rs`
+///
+/// # Standard section(s)
+///
+/// 6.6
+pub struct InterCommunicator(pub(crate) UserCommunicator);
+
+impl InterCommunicator {
+    /// Given a valid `MPI_Comm` handle in `raw`, returns an `InterCommunicator` value if, and
+    /// only if:
+    /// - The handle is not `MPI_COMM_NULL`
+    /// - The communicator is an inter-communicator
+    ///
+    /// Otherwise returns None.
+    ///
+    /// # Safety
+    /// - `raw` must be a live MPI_Comm object.
+    /// - `raw` must not be used after calling `from_raw`.
+    pub unsafe fn from_raw(raw: MPI_Comm) -> Option<InterCommunicator> {
+        UserCommunicator::from_raw(raw).and_then(|comm| {
+            let (_, flag) = with_uninitialized(|flag| ffi::MPI_Comm_test_inter(raw, flag));
+            if flag != 0 {
+                Some(InterCommunicator(comm))
+            } else {
+                // Forget the comm object so it's not dropped
+                mem::forget(comm);
+
+                None
+            }
+        })
+    }
+
+    /// Creates an `InterCommunicator` from `raw`.
+    ///
+    /// # Safety
+    /// - `raw` must be a live inter-communicator MPI_Comm object.
+    /// - `raw` must not be used after calling `from_raw_unchecked`.
+    /// - `raw` must not be `MPI_COMM_NULL`.
+    pub unsafe fn from_raw_unchecked(raw: MPI_Comm) -> InterCommunicator {
+        debug_assert_ne!(raw, ffi::RSMPI_COMM_NULL);
+        InterCommunicator(UserCommunicator::from_raw_unchecked(raw))
+    }
+
+    /// Number of processes in the remote group of this communicator
+    ///
+    /// # Examples
+    /// See `examples/intercommunicator.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.1
+    pub fn remote_size(&self) -> Rank {
+        unsafe { with_uninitialized(|size| ffi::MPI_Comm_remote_size(self.as_raw(), size)).1 }
+    }
+
+    /// The remote group of this communicator
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.1
+    pub fn remote_group(&self) -> UserGroup {
+        unsafe {
+            UserGroup(
+                with_uninitialized(|group| ffi::MPI_Comm_remote_group(self.as_raw(), group)).1,
+            )
+        }
+    }
+
+    /// Merges the local and remote group into a new intra-communicator.
+    ///
+    /// All processes of a group should pass the same value for `high`. The processes of the group
+    /// that passes `false` are ordered before those of the group that passes `true`. If both
+    /// groups pass the same value the order is arbitrary.
+    ///
+    /// # Examples
+    /// See `examples/intercommunicator.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.2
+    pub fn merge(&self, high: bool) -> UserCommunicator {
+        unsafe {
+            UserCommunicator::from_raw_unchecked(
+                with_uninitialized(|newcomm| {
+                    ffi::MPI_Intercomm_merge(self.as_raw(), high as c_int, newcomm)
+                })
+                .1,
+            )
+        }
+    }
+}
+
+impl Communicator for InterCommunicator {
+    fn size(&self) -> Rank {
+        self.0.size()
+    }
+
+    fn rank(&self) -> Rank {
+        self.0.rank()
+    }
+
+    fn target_size(&self) -> Rank {
+        self.remote_size()
+    }
+}
+
+impl AsCommunicator for InterCommunicator {
+    type Out = InterCommunicator;
+    fn as_communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+unsafe impl AsRaw for InterCommunicator {
+    type Raw = MPI_Comm;
+    fn as_raw(&self) -> Self::Raw {
+        self.0.as_raw()
+    }
+}
+
+impl From<InterCommunicator> for UserCommunicator {
+    fn from(inter_comm: InterCommunicator) -> Self {
+        inter_comm.0
+    }
+}
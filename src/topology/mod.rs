@@ -8,35 +8,35 @@
 //!
 //! # Unfinished features
 //!
-//! - **6.3**: Group management
-//!   - **6.3.2**: Constructors, `MPI_Group_range_incl()`, `MPI_Group_range_excl()`
 //! - **6.4**: Communicator management
-//!   - **6.4.2**: Constructors, `MPI_Comm_dup_with_info()`, `MPI_Comm_idup()`,
-//!     `MPI_Comm_split_type()`
 //!   - **6.4.4**: Info, `MPI_Comm_set_info()`, `MPI_Comm_get_info()`
-//! - **6.6**: Inter-communication
-//! - **6.7**: Caching
-//! - **6.8**: Naming objects
+//! - **6.7**: Caching on windows and datatypes
+//! - **6.8**: Naming windows, `MPI_Win_set_name()`, `MPI_Win_get_name()`
 //! - **7**: Process topologies
 //! - **Parts of sections**: 8, 10, 12
-use std::ffi::{CStr, CString};
-use std::mem::MaybeUninit;
-use std::os::raw::{c_char, c_int};
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::{c_int, c_void};
 use std::process;
 
 use conv::ConvUtil;
+use once_cell::sync::OnceCell;
 
-#[cfg(not(msmpi))]
-use crate::Tag;
-use crate::{Count, IntArray};
+use crate::{undefined, Count, IntArray, Tag};
 
 use crate::datatype::traits::*;
+use crate::environment::ErrorHandler;
 use crate::ffi;
 use crate::ffi::{MPI_Comm, MPI_Group};
+use crate::info::Info;
 use crate::raw::traits::*;
-use crate::with_uninitialized;
+use crate::request::{abort_on_unhandled_request, Request, StaticScope};
+use crate::{get_object_name, object_name_to_c, with_uninitialized, with_uninitialized2};
 
+mod attribute;
 mod cartesian;
+mod halo;
+mod intercommunicator;
 
 /// Topology traits
 pub mod traits {
@@ -44,7 +44,10 @@ pub mod traits {
 }
 
 // Re-export cartesian functions and types from topology modules.
+pub use self::attribute::*;
 pub use self::cartesian::*;
+pub use self::halo::*;
+pub use self::intercommunicator::*;
 
 /// Something that has a communicator associated with it
 pub trait AsCommunicator {
@@ -55,8 +58,55 @@ pub trait AsCommunicator {
 }
 
 /// Identifies a certain process within a communicator.
+///
+/// Like `Count`, this is an MPI `int`. Convert indices of type `usize` with `TryFrom` to catch
+/// values that are out of range instead of truncating them:
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(Ok(3), mpi::topology::Rank::try_from(3usize));
+/// assert!(mpi::topology::Rank::try_from(usize::MAX).is_err());
+/// ```
+///
+/// Like `Count`, `Rank` is an alias rather than a newtype.
 pub type Rank = c_int;
 
+/// The rank of the null process, `MPI_PROC_NULL`
+///
+/// Communication with the null process succeeds immediately without transferring any data, see
+/// `Communicator::null_process()`.
+///
+/// # Standard section(s)
+///
+/// 3.11
+pub fn proc_null() -> Rank {
+    unsafe { ffi::RSMPI_PROC_NULL }
+}
+
+/// The wildcard rank that matches messages from any source, `MPI_ANY_SOURCE`
+///
+/// See `Communicator::any_process()`.
+///
+/// # Standard section(s)
+///
+/// 3.2.4
+pub fn any_source() -> Rank {
+    unsafe { ffi::RSMPI_ANY_SOURCE }
+}
+
+/// The rank passed as the root of a collective on an inter-communicator by the root, `MPI_ROOT`
+///
+/// The other processes in the group of the root pass `proc_null()`, the processes in the remote
+/// group pass the rank of the root in the group of the root.
+///
+/// # Standard section(s)
+///
+/// 5.2.2
+pub fn root_sentinel() -> Rank {
+    unsafe { ffi::RSMPI_ROOT }
+}
+
 /// A built-in communicator, e.g. `MPI_COMM_WORLD`
 ///
 /// # Standard section(s)
@@ -100,7 +150,25 @@ unsafe impl AsRaw for SystemCommunicator {
     }
 }
 
-impl Communicator for SystemCommunicator {}
+impl Communicator for SystemCommunicator {
+    fn size(&self) -> Rank {
+        static WORLD_SIZE: OnceCell<Rank> = OnceCell::new();
+        if self.0 == unsafe { ffi::RSMPI_COMM_WORLD } {
+            *WORLD_SIZE.get_or_init(|| comm_size(self.0))
+        } else {
+            comm_size(self.0)
+        }
+    }
+
+    fn rank(&self) -> Rank {
+        static WORLD_RANK: OnceCell<Rank> = OnceCell::new();
+        if self.0 == unsafe { ffi::RSMPI_COMM_WORLD } {
+            *WORLD_RANK.get_or_init(|| comm_rank(self.0))
+        } else {
+            comm_rank(self.0)
+        }
+    }
+}
 
 impl AsCommunicator for SystemCommunicator {
     type Out = SystemCommunicator;
@@ -136,10 +204,17 @@ pub enum IntoTopology {
 
 /// A user-defined communicator
 ///
+/// The rank of the calling process and the size of the communicator are queried once when the
+/// raw handle is wrapped, `rank()` and `size()` return the cached values.
+///
 /// # Standard section(s)
 ///
 /// 6.4
-pub struct UserCommunicator(MPI_Comm);
+pub struct UserCommunicator {
+    raw: MPI_Comm,
+    rank: Rank,
+    size: Rank,
+}
 
 impl UserCommunicator {
     /// If the raw value is the null handle returns `None`
@@ -151,7 +226,7 @@ impl UserCommunicator {
         if raw == ffi::RSMPI_COMM_NULL {
             None
         } else {
-            Some(UserCommunicator(raw))
+            Some(UserCommunicator::from_raw_unchecked(raw))
         }
     }
 
@@ -163,7 +238,11 @@ impl UserCommunicator {
     /// - `raw` must not be `MPI_COMM_NULL`.
     unsafe fn from_raw_unchecked(raw: MPI_Comm) -> UserCommunicator {
         debug_assert_ne!(raw, ffi::RSMPI_COMM_NULL);
-        UserCommunicator(raw)
+        UserCommunicator {
+            raw,
+            rank: comm_rank(raw),
+            size: comm_size(raw),
+        }
     }
 
     /// Gets the topology of the communicator.
@@ -201,6 +280,95 @@ impl UserCommunicator {
             Topology::Undefined => IntoTopology::Undefined(self),
         }
     }
+
+    /// Frees the communicator.
+    ///
+    /// Dropping a `UserCommunicator` frees it as well, but since freeing is a collective
+    /// operation, all processes have to do so in the same order relative to other collective
+    /// operations on the parent communicator. Calling `free()` makes that point explicit instead of
+    /// relying on the drop order of the surrounding scopes. Other communicator types can be freed
+    /// by converting them into a `UserCommunicator` first.
+    ///
+    /// # Examples
+    /// See `examples/duplicate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.3
+    pub fn free(self) {
+        let mut raw = self.raw;
+        mem::forget(self);
+        unsafe {
+            ffi::MPI_Comm_free(&mut raw);
+        }
+        debug_assert_eq!(raw, unsafe { ffi::RSMPI_COMM_NULL });
+    }
+}
+
+/// A duplicate of a communicator that is still being set up
+///
+/// Returned by `Communicator::immediate_duplicate()`. The new `UserCommunicator` is only handed out
+/// by `wait()` and `test()` once the duplication has completed, so it cannot be used too early.
+/// The duplicated communicator stays borrowed for the lifetime `'a`.
+///
+/// # Panics
+///
+/// Dropping a `PendingCommunicator` before the duplication has completed aborts the process.
+///
+/// # Standard section(s)
+///
+/// 6.4.2
+#[must_use]
+#[derive(Debug)]
+pub struct PendingCommunicator<'a> {
+    // boxed, because MPI writes the new handle to this location on completion
+    comm: Box<MPI_Comm>,
+    request: Option<Request<'static>>,
+    phantom: PhantomData<&'a UserCommunicator>,
+}
+
+impl<'a> PendingCommunicator<'a> {
+    /// Wait for the duplication to complete and return the new communicator.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait(mut self) -> UserCommunicator {
+        self.request
+            .take()
+            .expect("duplication has already completed")
+            .wait_without_status();
+        unsafe { UserCommunicator::from_raw_unchecked(*self.comm) }
+    }
+
+    /// Test whether the duplication has completed.
+    ///
+    /// Returns the new communicator if it has, or the unfinished `PendingCommunicator` otherwise.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test(mut self) -> Result<UserCommunicator, Self> {
+        let request = self
+            .request
+            .take()
+            .expect("duplication has already completed");
+        match request.test() {
+            Ok(_) => Ok(unsafe { UserCommunicator::from_raw_unchecked(*self.comm) }),
+            Err(request) => {
+                self.request = Some(request);
+                Err(self)
+            }
+        }
+    }
+}
+
+impl<'a> Drop for PendingCommunicator<'a> {
+    fn drop(&mut self) {
+        if self.request.is_some() {
+            abort_on_unhandled_request();
+        }
+    }
 }
 
 impl AsCommunicator for UserCommunicator {
@@ -213,18 +381,26 @@ impl AsCommunicator for UserCommunicator {
 unsafe impl AsRaw for UserCommunicator {
     type Raw = MPI_Comm;
     fn as_raw(&self) -> Self::Raw {
-        self.0
+        self.raw
     }
 }
 
-impl Communicator for UserCommunicator {}
+impl Communicator for UserCommunicator {
+    fn size(&self) -> Rank {
+        self.size
+    }
+
+    fn rank(&self) -> Rank {
+        self.rank
+    }
+}
 
 impl Drop for UserCommunicator {
     fn drop(&mut self) {
         unsafe {
-            ffi::MPI_Comm_free(&mut self.0);
+            ffi::MPI_Comm_free(&mut self.raw);
         }
-        assert_eq!(self.0, unsafe { ffi::RSMPI_COMM_NULL });
+        assert_eq!(self.raw, unsafe { ffi::RSMPI_COMM_NULL });
     }
 }
 
@@ -271,10 +447,39 @@ impl Color {
 /// A key used when determining the rank order of processes after a communicator split.
 pub type Key = c_int;
 
+/// The criterion used to split a communicator via `split_by_type`
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SplitType {
+    /// Processes that can create a shared memory region join the same communicator
+    Shared,
+    /// The process does not join a new communicator
+    Undefined,
+}
+
+impl SplitType {
+    /// The raw value understood by the MPI C API
+    fn as_raw(self) -> c_int {
+        match self {
+            SplitType::Shared => unsafe { ffi::RSMPI_COMM_TYPE_SHARED },
+            SplitType::Undefined => unsafe { ffi::RSMPI_UNDEFINED },
+        }
+    }
+}
+
+fn comm_size(comm: MPI_Comm) -> Rank {
+    unsafe { with_uninitialized(|size| ffi::MPI_Comm_size(comm, size)).1 }
+}
+
+fn comm_rank(comm: MPI_Comm) -> Rank {
+    unsafe { with_uninitialized(|rank| ffi::MPI_Comm_rank(comm, rank)).1 }
+}
+
 /// Communicators are contexts for communication
 pub trait Communicator: AsRaw<Raw = MPI_Comm> {
     /// Number of processes in this communicator
     ///
+    /// The communicator types of rsmpi cache this value, so it is cheap to call e.g. in loops.
+    ///
     /// # Examples
     /// See `examples/simple.rs`
     ///
@@ -282,11 +487,13 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
     ///
     /// 6.4.1
     fn size(&self) -> Rank {
-        unsafe { with_uninitialized(|size| ffi::MPI_Comm_size(self.as_raw(), size)).1 }
+        comm_size(self.as_raw())
     }
 
     /// The `Rank` that identifies the calling process within this communicator
     ///
+    /// The communicator types of rsmpi cache this value, so it is cheap to call e.g. in loops.
+    ///
     /// # Examples
     /// See `examples/simple.rs`
     ///
@@ -294,7 +501,15 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
     ///
     /// 6.4.1
     fn rank(&self) -> Rank {
-        unsafe { with_uninitialized(|rank| ffi::MPI_Comm_rank(self.as_raw(), rank)).1 }
+        comm_rank(self.as_raw())
+    }
+
+    /// Number of processes that can be addressed via `process_at_rank()`
+    ///
+    /// This is the size of the communicator for intra-communicators and the size of the remote
+    /// group for inter-communicators.
+    fn target_size(&self) -> Rank {
+        self.size()
     }
 
     /// Bundles a reference to this communicator with a specific `Rank` into a `Process`.
@@ -305,12 +520,19 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
     where
         Self: Sized,
     {
-        assert!(0 <= r && r < self.size());
+        assert!(0 <= r && r < self.target_size());
         Process::by_rank_unchecked(self, r)
     }
 
     /// Returns an `AnyProcess` identifier that can be used, e.g. as a `Source` in point to point
     /// communication.
+    ///
+    /// Receiving from an `AnyProcess` matches messages from any source (`MPI_ANY_SOURCE`). The
+    /// receive functions without a `_with_tag` suffix additionally match any tag
+    /// (`MPI_ANY_TAG`). The actual source and tag can be retrieved from the returned `Status`.
+    ///
+    /// # Examples
+    /// See `examples/any_source.rs`
     fn any_process(&self) -> AnyProcess<Self>
     where
         Self: Sized,
@@ -327,9 +549,34 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
         Process::by_rank_unchecked(self, rank)
     }
 
+    /// A `Process` that represents no process at all, i.e. `MPI_PROC_NULL`
+    ///
+    /// Point to point communication with the null process succeeds immediately without
+    /// transferring any data. A receive from the null process leaves the buffer untouched and
+    /// returns a `Status` with source `MPI_PROC_NULL` and a count of zero. This avoids special
+    /// cases for processes at the boundaries of a domain, e.g. when a shift in a non-periodic
+    /// cartesian topology leaves the domain.
+    ///
+    /// # Examples
+    /// See `examples/null_process.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.11
+    fn null_process(&self) -> Process<Self>
+    where
+        Self: Sized,
+    {
+        Process::by_rank_unchecked(self, unsafe { ffi::RSMPI_PROC_NULL })
+    }
+
     /// Compare two communicators.
     ///
-    /// See enum `CommunicatorRelation`.
+    /// See enum `CommunicatorRelation`. Unlike comparing raw handles, this tells apart
+    /// communicators that merely share the same group from those that are identical.
+    ///
+    /// # Examples
+    /// See `examples/duplicate.rs`
     ///
     /// # Standard section(s)
     ///
@@ -347,6 +594,11 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
 
     /// Duplicate a communicator.
     ///
+    /// The new communicator has the same group of processes as the original one but a distinct
+    /// communication context, i.e. messages sent on one of them can never be received on the
+    /// other. Libraries should use a duplicate of the communicator they are handed to keep their
+    /// internal traffic separate from that of the user.
+    ///
     /// # Examples
     ///
     /// See `examples/duplicate.rs`
@@ -362,6 +614,127 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
         }
     }
 
+    /// Start duplicating a communicator without blocking.
+    ///
+    /// Like `duplicate()` but returns immediately, so that the set-up of the new communicator can
+    /// overlap with other work. The new communicator becomes available through
+    /// `PendingCommunicator::wait()` or `PendingCommunicator::test()` once the duplication has
+    /// completed and cannot be used before. This communicator stays borrowed until then.
+    ///
+    /// This is a collective operation, all processes of the communicator have to call it.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/duplicate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2
+    fn immediate_duplicate(&self) -> PendingCommunicator<'_> {
+        let mut comm = Box::new(unsafe { ffi::RSMPI_COMM_NULL });
+        unsafe {
+            let request = with_uninitialized(|request| {
+                ffi::MPI_Comm_idup(self.as_raw(), &mut *comm, request)
+            })
+            .1;
+            PendingCommunicator {
+                comm,
+                request: Some(Request::from_raw(request, StaticScope)),
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Duplicate a communicator, passing hints to the MPI implementation.
+    ///
+    /// Like `duplicate()` but the hints in `info` are associated with the new communicator instead
+    /// of the hints of this communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/info.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2
+    fn duplicate_with_info(&self, info: &Info) -> UserCommunicator {
+        unsafe {
+            UserCommunicator::from_raw_unchecked(
+                with_uninitialized(|newcomm| {
+                    ffi::MPI_Comm_dup_with_info(self.as_raw(), info.as_raw(), newcomm)
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Create an inter-communicator connecting the group of this communicator with another,
+    /// disjoint group of processes.
+    ///
+    /// Both groups choose a local leader, `local_leader` is its rank in this communicator. The
+    /// leaders communicate with each other via `peer_comm`, in which the leader of the remote
+    /// group has rank `remote_leader`. `peer_comm` and `remote_leader` are only significant on
+    /// the local leader.
+    ///
+    /// # Examples
+    /// See `examples/intercommunicator.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.2
+    fn create_intercommunicator<C: ?Sized>(
+        &self,
+        local_leader: Rank,
+        peer_comm: &C,
+        remote_leader: Rank,
+    ) -> InterCommunicator
+    where
+        C: Communicator,
+    {
+        self.create_intercommunicator_with_tag(
+            local_leader,
+            peer_comm,
+            remote_leader,
+            Tag::default(),
+        )
+    }
+
+    /// Create an inter-communicator connecting the group of this communicator with another,
+    /// disjoint group of processes.
+    ///
+    /// Like `create_intercommunicator()` but the leaders communicate using `tag` so that the call
+    /// does not interfere with other communication on `peer_comm`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.2
+    fn create_intercommunicator_with_tag<C: ?Sized>(
+        &self,
+        local_leader: Rank,
+        peer_comm: &C,
+        remote_leader: Rank,
+        tag: Tag,
+    ) -> InterCommunicator
+    where
+        C: Communicator,
+    {
+        unsafe {
+            InterCommunicator::from_raw_unchecked(
+                with_uninitialized(|newintercomm| {
+                    ffi::MPI_Intercomm_create(
+                        self.as_raw(),
+                        local_leader,
+                        peer_comm.as_raw(),
+                        remote_leader,
+                        tag,
+                        newintercomm,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
     /// Split a communicator by color.
     ///
     /// Creates as many new communicators as distinct values of `color` are given. All processes
@@ -398,6 +771,39 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
         }
     }
 
+    /// Split a communicator by type.
+    ///
+    /// Processes are grouped into new communicators according to `split_type`, e.g. all processes
+    /// that can create a shared memory region with each other join the same communicator when
+    /// passing `SplitType::Shared`. A process that passes `SplitType::Undefined` will not join a
+    /// new communicator and `None` is returned.
+    ///
+    /// Within each subgroup, the processes are ranked in the order defined by the value of the
+    /// argument key, with ties broken according to their rank in the old group.
+    ///
+    /// # Examples
+    /// See `examples/split_type.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2 (See: `MPI_Comm_split_type`)
+    fn split_by_type(&self, split_type: SplitType, key: Key) -> Option<UserCommunicator> {
+        unsafe {
+            UserCommunicator::from_raw(
+                with_uninitialized(|newcomm| {
+                    ffi::MPI_Comm_split_type(
+                        self.as_raw(),
+                        split_type.as_raw(),
+                        key,
+                        ffi::RSMPI_INFO_NULL,
+                        newcomm,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
     /// Split the communicator into subcommunicators, each of which can create a shared memory
     /// region.
     ///
@@ -509,8 +915,143 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
         }
     }
 
+    /// Find the rank in communicator `other` of the process that has rank `rank` in this
+    /// communicator.
+    ///
+    /// If the process is not a member of `other`, returns `None`.
+    ///
+    /// # Examples
+    /// See `examples/split.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.3.1
+    fn translate_rank_to<C: ?Sized>(&self, rank: Rank, other: &C) -> Option<Rank>
+    where
+        C: Communicator,
+    {
+        self.group().translate_rank(rank, &other.group())
+    }
+
+    /// Find the ranks in communicator `other` of the processes that have ranks `ranks` in this
+    /// communicator.
+    ///
+    /// If a process is not a member of `other`, returns `None` in its place.
+    ///
+    /// # Examples
+    /// See `examples/split.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.3.1
+    fn translate_ranks_to<C: ?Sized>(&self, ranks: &[Rank], other: &C) -> Vec<Option<Rank>>
+    where
+        C: Communicator,
+    {
+        self.group().translate_ranks(ranks, &other.group())
+    }
+
+    /// Sets the handler that is invoked when an MPI function raises an error on this
+    /// communicator.
+    ///
+    /// With `ErrorHandler::Return`, errors are no longer fatal.
+    ///
+    /// # Examples
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Safety
+    ///
+    /// Most functions in this crate ignore the error codes of the MPI functions they call and
+    /// assume that output arguments have been written. While `ErrorHandler::Return` is set, the
+    /// caller must only use functions on this communicator that report errors, e.g.
+    /// `CommunicatorCollectives::all_reduce_checked()` or raw MPI calls whose codes are checked,
+    /// until `ErrorHandler::Fatal` is restored. Setting `ErrorHandler::Fatal` is always safe.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1
+    unsafe fn set_error_handler(&self, handler: ErrorHandler) {
+        ffi::MPI_Comm_set_errhandler(self.as_raw(), handler.as_raw());
+    }
+
+    /// Caches `value` on this communicator under the key `keyval`.
+    ///
+    /// A value that has previously been cached under the same key is dropped.
+    ///
+    /// # Examples
+    /// See `examples/attributes.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.7.2
+    fn set_attr<T: 'static>(&self, keyval: &CommKeyval<T>, value: T) {
+        let value = Box::into_raw(Box::new(value));
+        unsafe {
+            ffi::MPI_Comm_set_attr(self.as_raw(), keyval.as_raw(), value as *mut c_void);
+        }
+    }
+
+    /// Retrieves a copy of the value cached on this communicator under the key `keyval`.
+    ///
+    /// Returns `None` if no value is cached under `keyval`. To share mutable state, cache e.g. an
+    /// `Arc<Mutex<_>>`.
+    ///
+    /// # Examples
+    /// See `examples/attributes.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.7.2
+    fn get_attr<T: Clone + 'static>(&self, keyval: &CommKeyval<T>) -> Option<T> {
+        let (_, value, flag) = unsafe {
+            with_uninitialized2(|value: *mut *const T, flag| {
+                ffi::MPI_Comm_get_attr(self.as_raw(), keyval.as_raw(), value as *mut c_void, flag)
+            })
+        };
+        if flag != 0 {
+            Some(unsafe { (*value).clone() })
+        } else {
+            None
+        }
+    }
+
+    /// Removes the value cached on this communicator under the key `keyval` and drops it.
+    ///
+    /// # Examples
+    /// See `examples/attributes.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.7.2
+    fn delete_attr<T: 'static>(&self, keyval: &CommKeyval<T>) {
+        unsafe {
+            ffi::MPI_Comm_delete_attr(self.as_raw(), keyval.as_raw());
+        }
+    }
+
     /// Abort program execution
     ///
+    /// Makes a best attempt to terminate all processes in the group of this communicator with
+    /// `errorcode` as the exit status. Calling `abort` on the world communicator is the last
+    /// resort for errors a job cannot recover from. Unlike a panic, which only terminates the
+    /// calling process and can leave other processes waiting forever, it brings down the whole
+    /// job. It can be called from a panic hook to make any panic terminate the job.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mpi::topology::SystemCommunicator;
+    /// use mpi::traits::*;
+    ///
+    /// let universe = mpi::initialize().unwrap();
+    ///
+    /// let default_hook = std::panic::take_hook();
+    /// std::panic::set_hook(Box::new(move |info| {
+    ///     default_hook(info);
+    ///     SystemCommunicator::world().abort(1);
+    /// }));
+    /// ```
+    ///
     /// # Standard section(s)
     ///
     /// 8.7
@@ -523,11 +1064,17 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
 
     /// Set the communicator name
     ///
+    /// The name is shown by debuggers and profiling tools. It is truncated to fit into
+    /// `MPI_MAX_OBJECT_NAME` bytes.
+    ///
+    /// # Examples
+    /// See `examples/comm_name.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 6.8, see the `MPI_Comm_set_name` function
     fn set_name(&self, name: &str) {
-        let c_name = CString::new(name).expect("Failed to convert the Rust string to a C string");
+        let c_name = object_name_to_c(name);
         unsafe {
             ffi::MPI_Comm_set_name(self.as_raw(), c_name.as_ptr());
         }
@@ -535,21 +1082,17 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
 
     /// Get the communicator name
     ///
+    /// # Examples
+    /// See `examples/comm_name.rs`
+    ///
     /// # Standard section(s)
     ///
     /// 6.8, see the `MPI_Comm_get_name` function
     fn get_name(&self) -> String {
-        type BufType = [c_char; ffi::MPI_MAX_OBJECT_NAME as usize];
-
         unsafe {
-            let mut buf = MaybeUninit::<BufType>::uninit();
-
-            let (_, _resultlen) = with_uninitialized(|resultlen| {
-                ffi::MPI_Comm_get_name(self.as_raw(), &mut (*buf.as_mut_ptr())[0], resultlen)
-            });
-
-            let buf_cstr = CStr::from_ptr(buf.assume_init().as_ptr());
-            buf_cstr.to_string_lossy().into_owned()
+            get_object_name(|name, resultlen| {
+                ffi::MPI_Comm_get_name(self.as_raw(), name, resultlen)
+            })
         }
     }
 
@@ -650,6 +1193,26 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
         }
     }
 
+    /// Gets the implementation-defined buffer size required to pack the contents of `inbuf`.
+    ///
+    /// The result is an upper bound in bytes, suitable for pre-allocating the output buffer of one
+    /// or more calls to `pack_into`.
+    ///
+    /// # Examples
+    /// See `examples/pack_into.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2, see MPI_Pack_size
+    fn packed_size<Buf>(&self, inbuf: &Buf) -> usize
+    where
+        Buf: ?Sized + Buffer,
+    {
+        self.pack_size(inbuf.count(), &inbuf.as_datatype())
+            .value_as()
+            .expect("MPI returned a negative pack size.")
+    }
+
     /// Packs inbuf into a byte array with an implementation-defined format. Often paired with
     /// `unpack` to convert back into a specific datatype.
     ///
@@ -684,6 +1247,13 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
     /// Packs inbuf into a byte array with an implementation-defined format. Often paired with
     /// `unpack` to convert back into a specific datatype.
     ///
+    /// Packing starts at byte offset `position` in `outbuf`, the returned value is the position
+    /// following the packed data, so multiple buffers can be packed into `outbuf` one after the
+    /// other.
+    ///
+    /// # Examples
+    /// See `examples/pack_into.rs`
+    ///
     /// # Standard Sections
     ///
     /// 4.2, see MPI_Pack
@@ -732,6 +1302,37 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
         );
         position
     }
+
+    /// Gives the MPI library an opportunity to progress pending non-blocking operations.
+    ///
+    /// Many MPI libraries only advance non-blocking operations while the application is inside
+    /// the library. Calling this method every now and then in a long running computation, e.g.
+    /// once per iteration of a loop, enters the library via a probe for any message on this
+    /// communicator whose result is discarded, without having to test every pending request.
+    ///
+    /// This is a best-effort hint, the standard does not guarantee that it makes progress on any
+    /// operation. To make sure operations make progress in the background, see
+    /// `request::ProgressEngine`.
+    ///
+    /// # Examples
+    /// See `examples/immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.1, 3.5
+    fn progress(&self) {
+        unsafe {
+            with_uninitialized(|flag| {
+                ffi::MPI_Iprobe(
+                    ffi::RSMPI_ANY_SOURCE,
+                    ffi::RSMPI_ANY_TAG,
+                    self.as_raw(),
+                    flag,
+                    ffi::RSMPI_STATUS_IGNORE,
+                )
+            });
+        }
+    }
 }
 
 /// The relation between two communicators.
@@ -739,7 +1340,7 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
 /// # Standard section(s)
 ///
 /// 6.4.1
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum CommunicatorRelation {
     /// Identical groups and same contexts
     Identical,
@@ -797,6 +1398,11 @@ where
     pub fn rank(&self) -> Rank {
         self.rank
     }
+
+    /// Whether this is the null process, see `Communicator::null_process()`
+    pub fn is_null(&self) -> bool {
+        self.rank == unsafe { ffi::RSMPI_PROC_NULL }
+    }
 }
 
 impl<'a, C> AsCommunicator for Process<'a, C>
@@ -980,6 +1586,69 @@ pub trait Group: AsRaw<Raw = MPI_Group> {
         }
     }
 
+    /// Subgroup including ranges of ranks
+    ///
+    /// Each range is a triple `(first, last, stride)` that denotes the ranks `first`,
+    /// `first + stride`, ... up to and including `last`. Constructs a new group that contains the
+    /// processes denoted by all ranges in the order in which they appear.
+    ///
+    /// # Examples
+    /// See `examples/group.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.3.2
+    fn range_include(&self, ranges: &[(Rank, Rank, Rank)]) -> UserGroup {
+        let mut ranges: Vec<[Rank; 3]> = ranges
+            .iter()
+            .map(|&(first, last, stride)| [first, last, stride])
+            .collect();
+        unsafe {
+            UserGroup(
+                with_uninitialized(|newgroup| {
+                    ffi::MPI_Group_range_incl(
+                        self.as_raw(),
+                        ranges.count(),
+                        ranges.as_mut_ptr(),
+                        newgroup,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Subgroup excluding ranges of ranks
+    ///
+    /// Each range is a triple `(first, last, stride)` like in `range_include`. Constructs a new
+    /// group containing those processes from the old group that are not denoted by any range.
+    ///
+    /// # Examples
+    /// See `examples/group.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.3.2
+    fn range_exclude(&self, ranges: &[(Rank, Rank, Rank)]) -> UserGroup {
+        let mut ranges: Vec<[Rank; 3]> = ranges
+            .iter()
+            .map(|&(first, last, stride)| [first, last, stride])
+            .collect();
+        unsafe {
+            UserGroup(
+                with_uninitialized(|newgroup| {
+                    ffi::MPI_Group_range_excl(
+                        self.as_raw(),
+                        ranges.count(),
+                        ranges.as_mut_ptr(),
+                        newgroup,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
     /// Number of processes in the group.
     ///
     /// # Standard section(s)
@@ -997,7 +1666,7 @@ pub trait Group: AsRaw<Raw = MPI_Group> {
     fn rank(&self) -> Option<Rank> {
         unsafe {
             let (_, rank) = with_uninitialized(|rank| ffi::MPI_Group_rank(self.as_raw(), rank));
-            if rank == ffi::RSMPI_UNDEFINED {
+            if rank == undefined() {
                 None
             } else {
                 Some(rank)
@@ -1020,7 +1689,7 @@ pub trait Group: AsRaw<Raw = MPI_Group> {
             let (_, translated) = with_uninitialized(|translated| {
                 ffi::MPI_Group_translate_ranks(self.as_raw(), 1, &rank, other.as_raw(), translated)
             });
-            if translated == ffi::RSMPI_UNDEFINED {
+            if translated == undefined() {
                 None
             } else {
                 Some(translated)
@@ -0,0 +1,232 @@
+//! One-sided communication
+//!
+//! MPI windows expose memory of a process to the other processes of a communicator. A
+//! `SharedWindow` is a window whose memory is shared between processes on the same node, so that
+//! every process can load from and store to the segments of all other processes directly, without
+//! passing messages.
+//!
+//! # Unfinished features
+//!
+//! - **11.2**: Window creation, `MPI_Win_create()`, `MPI_Win_allocate()`,
+//! `MPI_Win_create_dynamic()`, window attributes and info
+//! - **11.3**: Communication calls, `MPI_Put()`, `MPI_Get()`, `MPI_Accumulate()`, ...
+//! - **11.5**: Synchronization calls, general active target synchronization and passive target
+//! synchronization of single targets
+
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+use std::{mem, ptr, slice};
+
+use conv::ConvUtil;
+
+use crate::datatype::traits::*;
+use crate::ffi;
+use crate::ffi::MPI_Win;
+use crate::raw::traits::*;
+use crate::topology::traits::*;
+use crate::topology::Rank;
+use crate::{with_uninitialized2, Address};
+
+/// A window of memory that is shared between the processes of a node
+///
+/// Every process contributes a segment of `T`s, which the other processes can access in place via
+/// `query()`. Since the processes access the memory concurrently without the knowledge of the
+/// compiler, every access to a segment that another process may write to, including the own
+/// segment, has to be separated from that write by a synchronization call: either collectively
+/// via `fence()`, or within a `lock_all()` / `unlock_all()` epoch via `sync()` combined with a
+/// barrier or messages that order the accesses. Unsynchronized concurrent accesses are data races.
+///
+/// The window is freed when it is dropped, which is a collective operation.
+///
+/// # Examples
+/// See `examples/shared_window.rs`
+///
+/// # Standard section(s)
+///
+/// 11.2.3
+pub struct SharedWindow<T> {
+    win: MPI_Win,
+    base: *mut T,
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> SharedWindow<T>
+where
+    T: Equivalence + Copy,
+{
+    /// Allocates a shared window on `comm` with a segment of `len` elements on the calling
+    /// process, each initialized to `value`.
+    ///
+    /// All processes of `comm` have to be able to share memory, e.g. `comm` is the result of
+    /// `Communicator::split_shared()`. Different processes may contribute segments of different
+    /// lengths. This is a collective operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is zero-sized.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.2.3
+    pub fn allocate<C>(comm: &C, len: usize, value: T) -> Self
+    where
+        C: Communicator,
+    {
+        assert_ne!(
+            mem::size_of::<T>(),
+            0,
+            "Shared windows of zero-sized types are not supported."
+        );
+        let size: Address = (len * mem::size_of::<T>())
+            .value_as()
+            .expect("Size of window segment cannot be expressed as an MPI Address.");
+        let (_, base, win) = unsafe {
+            with_uninitialized2(|base: *mut *mut T, win| {
+                ffi::MPI_Win_allocate_shared(
+                    size,
+                    mem::size_of::<T>().value_as().unwrap(),
+                    ffi::RSMPI_INFO_NULL,
+                    comm.as_raw(),
+                    base as *mut c_void,
+                    win,
+                )
+            })
+        };
+        let mut window = SharedWindow {
+            win,
+            base,
+            len,
+            phantom: PhantomData,
+        };
+        for element in window.local_mut() {
+            *element = value;
+        }
+        window
+    }
+
+    /// The segment of the calling process
+    ///
+    /// See the type level documentation for the synchronization that is required before reading
+    /// values written by other processes.
+    pub fn local(&self) -> &[T] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(self.base, self.len) }
+    }
+
+    /// The segment of the calling process
+    ///
+    /// See the type level documentation for the synchronization that is required before other
+    /// processes may read the values written here.
+    pub fn local_mut(&mut self) -> &mut [T] {
+        if self.len == 0 {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.base, self.len) }
+    }
+
+    /// The segment of process `rank` along with its displacement unit in bytes
+    ///
+    /// The segment is mapped into the address space of the calling process, loads and stores go
+    /// directly to the shared memory.
+    ///
+    /// # Safety
+    ///
+    /// The returned slice aliases memory that other processes, and other slices returned by this
+    /// method or `local_mut()`, access at the same time. The caller has to ensure that no two
+    /// slices to the same segment are used concurrently within this process and that accesses
+    /// from different processes are separated by synchronization calls as described in the type
+    /// level documentation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.2.3
+    pub unsafe fn query(&self, rank: Rank) -> (&mut [T], Address) {
+        let mut size: Address = 0;
+        let mut disp_unit = 0;
+        let mut base: *mut T = ptr::null_mut();
+        ffi::MPI_Win_shared_query(
+            self.win,
+            rank,
+            &mut size,
+            &mut disp_unit,
+            &mut base as *mut *mut T as *mut c_void,
+        );
+        let len = size.value_as::<usize>().unwrap() / mem::size_of::<T>();
+        let segment = if len == 0 {
+            &mut []
+        } else {
+            slice::from_raw_parts_mut(base, len)
+        };
+        (segment, disp_unit.value_as().unwrap())
+    }
+
+    /// Synchronize all processes of the window collectively.
+    ///
+    /// Completes all accesses to the window before the fence and orders them before all accesses
+    /// after it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.5.1
+    pub fn fence(&self) {
+        unsafe {
+            ffi::MPI_Win_fence(0, self.win);
+        }
+    }
+
+    /// Start a passive target access epoch to the segments of all processes.
+    ///
+    /// Within the epoch, `sync()` makes stores visible to the other processes and loads observe
+    /// their stores. End the epoch with `unlock_all()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.5.3
+    pub fn lock_all(&self) {
+        unsafe {
+            ffi::MPI_Win_lock_all(0, self.win);
+        }
+    }
+
+    /// End the passive target access epoch started with `lock_all()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.5.3
+    pub fn unlock_all(&self) {
+        unsafe {
+            ffi::MPI_Win_unlock_all(self.win);
+        }
+    }
+
+    /// Synchronize the private and public copies of the window memory.
+    ///
+    /// Acts as a memory barrier for the shared memory within a passive target epoch.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.5.4
+    pub fn sync(&self) {
+        unsafe {
+            ffi::MPI_Win_sync(self.win);
+        }
+    }
+}
+
+unsafe impl<T> AsRaw for SharedWindow<T> {
+    type Raw = MPI_Win;
+    fn as_raw(&self) -> Self::Raw {
+        self.win
+    }
+}
+
+impl<T> Drop for SharedWindow<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_free(&mut self.win);
+        }
+    }
+}
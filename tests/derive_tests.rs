@@ -18,3 +18,67 @@ fn derive_equivalence() {
         field: [usize; 7],
     }
 }
+
+/// We test that #[derive(Equivalence)] accepts fieldless enums with a primitive representation.
+#[test]
+fn derive_equivalence_enum() {
+    #[derive(Equivalence)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Phase {
+        A,
+        B,
+        C,
+    }
+    #[derive(Equivalence)]
+    #[repr(i64)]
+    #[allow(dead_code)]
+    enum Direction {
+        Up = -1,
+        Down = 1,
+    }
+}
+
+fn assert_is_equivalence<T: Equivalence>() {}
+
+/// We test that #[derive(Equivalence)] accepts generic structs and bounds their type parameters.
+#[test]
+fn derive_equivalence_generic() {
+    #[derive(Equivalence)]
+    struct Packet<T> {
+        header: u32,
+        payload: T,
+    }
+    #[derive(Equivalence)]
+    struct Pair<A, B>(A, B)
+    where
+        A: Copy;
+
+    assert_is_equivalence::<Packet<f64>>();
+    assert_is_equivalence::<Packet<[u8; 4]>>();
+    assert_is_equivalence::<Packet<Packet<i16>>>();
+    assert_is_equivalence::<Pair<u8, Packet<bool>>>();
+}
+
+/// We test that #[derive(Equivalence)] accepts array fields of any length, including lengths
+/// given by const generic parameters.
+#[test]
+fn derive_equivalence_array() {
+    #[derive(Equivalence)]
+    struct Tensor {
+        values: [f64; 64],
+        shape: [[u16; 4]; 2],
+    }
+    #[derive(Equivalence)]
+    struct Coordinates<const N: usize> {
+        id: u64,
+        position: [f64; N],
+    }
+    #[derive(Equivalence)]
+    struct Samples<T, const N: usize>([T; N]);
+
+    assert_is_equivalence::<Tensor>();
+    assert_is_equivalence::<Coordinates<3>>();
+    assert_is_equivalence::<Coordinates<0>>();
+    assert_is_equivalence::<Samples<Coordinates<2>, 16>>();
+}